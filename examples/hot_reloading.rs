@@ -94,7 +94,7 @@ impl Application for Styling {
         Command::none()
     }
 
-    fn view(&mut self) -> Element<Message> {
+    fn view(&mut self) -> Element<'_, Message> {
         let text_input = self.theme.text_input().new(
             &mut self.input,
             "Type path to theme file...",
@@ -0,0 +1,155 @@
+//! Building themes in Rust code.
+
+use crate::import::*;
+
+/// A builder for [`Theme`](crate::Theme).
+///
+/// The builder produces the same theme as the deserializer produces from the
+/// equivalent configuration file.
+///
+/// # Examples
+///
+/// ```rust
+/// use iced::Color;
+/// use iced_style_config::Theme;
+///
+/// # fn main() -> Result<(), iced_style_config::Error> {
+/// let theme = Theme::builder()
+///     .color("ACCENT", Color::from_rgb(0.44, 1.0, 0.91))
+///     .button("primary", |b| {
+///         b.padding(10).active(|s| s.background("ACCENT").border_radius(3.0))
+///     })
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+#[must_use]
+pub struct ThemeBuilder(toml::value::Table);
+
+macro_rules! style_sheets {
+    ($($(#[$meta:meta])* $widget:literal: $module:ident,)*) => {
+        $(
+            #[doc = concat!("Sets the style sheet for [`", $widget, "`] with the given name.")]
+            ///
+            /// The style sheet named `"default"` is used by default.
+            $(#[$meta])*
+            pub fn $module(
+                mut self,
+                name: impl Into<String>,
+                f: impl FnOnce(
+                    crate::$module::StyleSheetBuilder,
+                ) -> crate::$module::StyleSheetBuilder,
+            ) -> Self {
+                let table = self.table(stringify!($module));
+                let name = name.into();
+                let style_sheet = crate::$module::StyleSheetBuilder::from_value(table.remove(&name));
+                table.insert(name, f(style_sheet).into_value());
+                self
+            }
+        )*
+    };
+}
+
+impl ThemeBuilder {
+    /// Defines a color alias.
+    pub fn color(mut self, name: impl Into<String>, color: impl Into<ColorValue>) -> Self {
+        self.table("color").insert(name.into(), color.into().to_value());
+        self
+    }
+
+    style_sheets! {
+        "iced::Button": button,
+        "iced::Checkbox": checkbox,
+        "iced::Container": container,
+        "iced::Image": image,
+        "iced::image::Viewer": image_viewer,
+        "iced::PickList": pick_list,
+        "iced::ProgressBar": progress_bar,
+        #[cfg(feature = "qr_code")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "qr_code")))]
+        "iced::QRCode": qr_code,
+        "iced::Radio": radio,
+        "iced::Scrollable": scrollable,
+        "iced::Slider": slider,
+        "iced::Svg": svg,
+        "iced::Text": text,
+        "iced::TextInput": text_input,
+    }
+
+    /// Builds the theme.
+    ///
+    /// Returns an error if a color alias used in the theme is not defined.
+    pub fn build(self) -> Result<crate::Theme, Error> {
+        crate::Theme::from_value(toml::Value::Table(self.0)).map_err(Error::new)
+    }
+
+    fn table(&mut self, key: &str) -> &mut toml::value::Table {
+        match self.0.entry(key).or_insert_with(|| toml::value::Table::new().into()) {
+            toml::Value::Table(table) => table,
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// A color or the name of a color alias, used by the builders.
+#[derive(Debug, Clone)]
+pub struct ColorValue(toml::Value);
+
+impl From<iced_native::Color> for ColorValue {
+    fn from(color: iced_native::Color) -> Self {
+        Self(Color::from(color).to_value())
+    }
+}
+
+impl From<&str> for ColorValue {
+    fn from(alias: &str) -> Self {
+        Self(alias.into())
+    }
+}
+
+impl From<String> for ColorValue {
+    fn from(alias: String) -> Self {
+        Self(alias.into())
+    }
+}
+
+/// Conversion into a value of the configuration file.
+pub(crate) trait ToValue {
+    fn to_value(self) -> toml::Value;
+}
+
+impl ToValue for ColorValue {
+    fn to_value(self) -> toml::Value {
+        self.0
+    }
+}
+
+impl ToValue for u16 {
+    fn to_value(self) -> toml::Value {
+        toml::Value::Integer(self.into())
+    }
+}
+
+impl ToValue for u32 {
+    fn to_value(self) -> toml::Value {
+        toml::Value::Integer(self.into())
+    }
+}
+
+impl ToValue for f32 {
+    fn to_value(self) -> toml::Value {
+        toml::Value::Float(self.into())
+    }
+}
+
+/// Serializes a value that is known to be representable in TOML.
+pub(crate) fn serialize(value: impl Serialize) -> toml::Value {
+    toml::Value::try_from(value).unwrap()
+}
+
+impl ToValue for Color {
+    fn to_value(self) -> toml::Value {
+        serialize(self)
+    }
+}
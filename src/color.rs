@@ -1,41 +1,127 @@
 use crate::import::*;
 
-#[derive(Debug, Clone, Copy)]
-pub(crate) struct Color(iced_native::Color);
+#[derive(Debug, Clone)]
+pub(crate) struct Color {
+    value: iced_native::Color,
+    /// The color as written in the configuration file. Aliases and CSS color
+    /// functions are serialized in this form, and other colors in hex form.
+    input: Box<de::Color>,
+}
+
+impl PartialEq for Color {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl From<iced_native::Color> for Color {
+    fn from(value: iced_native::Color) -> Self {
+        Self { value, input: Box::new(de::Color::new(value)) }
+    }
+}
 
 impl From<Color> for iced_native::Color {
     fn from(color: Color) -> Self {
-        color.0
+        color.value
+    }
+}
+
+impl From<&Color> for iced_native::Color {
+    fn from(color: &Color) -> Self {
+        color.value
     }
 }
 
-#[derive(Debug, Clone, Copy, Deserialize)]
-#[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(transparent)]
 pub(crate) struct Background(Color);
 
-impl From<Background> for iced_native::Background {
-    fn from(background: Background) -> Self {
-        Self::Color(background.0.into())
+impl From<&Background> for iced_native::Background {
+    fn from(background: &Background) -> Self {
+        Self::Color((&background.0).into())
     }
 }
 
+mod convert;
+pub use self::convert::{
+    from_hsl, from_hsv, from_oklab, from_oklch, to_hsl, to_hsv, to_oklab, to_oklch,
+};
+
+mod css;
 #[path = "gen/color_map.rs"]
 mod default_color_aliases;
+mod function;
 
 use default_color_aliases::DEFAULT_COLOR_ALIASES;
 
 scoped_thread_local!(pub(crate) static COLOR_ALIASES: RefCell<ColorMap>);
 
-#[derive(Debug, Default)]
-pub(crate) struct ColorAliases(pub(crate) ColorMap);
+/// An error in resolving a color.
+#[derive(Debug, Clone)]
+pub(crate) struct ColorError {
+    pub(crate) message: String,
+    /// A suggestion for fixing the error, e.g., ", did you mean `accent`?".
+    pub(crate) suggestion: String,
+    /// `true` if the error is caused by an undefined color alias.
+    pub(crate) unknown_alias: bool,
+}
+
+impl ColorError {
+    fn unknown_alias(name: &str, suggestion: String) -> Self {
+        Self {
+            message: format!("cannot found color alias `{name}`"),
+            suggestion,
+            unknown_alias: true,
+        }
+    }
+
+    /// Prepends `context` to the message, e.g., "invalid color `lighten(FOO, 10%)`".
+    pub(crate) fn context(mut self, context: impl fmt::Display) -> Self {
+        self.message = format!("{context}: {}", self.message);
+        self
+    }
+}
+
+impl From<String> for ColorError {
+    fn from(message: String) -> Self {
+        Self { message, suggestion: String::new(), unknown_alias: false }
+    }
+}
+
+impl fmt::Display for ColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.message, self.suggestion)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct ColorAliases {
+    pub(crate) map: ColorMap,
+    /// The colors as written in the configuration file.
+    colors: BTreeMap<String, Color>,
+}
+
+impl ColorAliases {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// Returns the names of the color aliases that shadow built-in color aliases.
+    pub(crate) fn shadowed_builtins(&self) -> impl Iterator<Item = &str> {
+        self.colors
+            .keys()
+            .map(|k| &**k)
+            .filter(|k| DEFAULT_COLOR_ALIASES.contains_key(&*k.to_ascii_lowercase()))
+    }
+}
 
 pub use self::map::ColorMap;
 
 mod map {
     use crate::import::*;
 
-    #[derive(Debug, Clone, Default)]
+    #[derive(Debug, Clone, Default, PartialEq)]
     pub struct ColorMap(HashMap<String, iced_native::Color>);
 
     impl ColorMap {
@@ -62,6 +148,11 @@ mod map {
             }
         }
 
+        /// Returns the names of the colors, in lowercase.
+        pub(crate) fn keys(&self) -> impl Iterator<Item = &str> {
+            self.0.keys().map(String::as_str)
+        }
+
         pub fn contains_key<S>(&self, k: S) -> bool
         where
             S: Borrow<str> + Into<String>,
@@ -97,13 +188,20 @@ mod map {
 }
 
 mod de {
-    use crate::import::*;
+    use super::ColorError;
+    use crate::{
+        error::{ParseError, Payload},
+        import::*,
+    };
 
-    #[derive(Debug, Deserialize)]
-    #[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+    #[derive(Debug, Clone, Deserialize, Serialize)]
+    #[cfg_attr(feature = "schema", derive(JsonSchema))]
     #[serde(untagged)]
     pub(super) enum Color {
-        Alias(String),
+        /// A hex color (e.g., `"#6FFFE9"`), a CSS color function (e.g.,
+        /// `"hsl(172deg 100% 72%)"`), or the name of a color alias.
+        #[cfg_attr(feature = "schema", schemars(schema_with = "super::schema::color_str"))]
+        Str(String),
         RgbTuple(f32, f32, f32),
         RgbStruct {
             r: f32,
@@ -122,72 +220,226 @@ mod de {
             a: f32,
         },
         Rgb8Tuple(
-            #[serde(deserialize_with = "from_hex")] u8,
-            #[serde(deserialize_with = "from_hex")] u8,
-            #[serde(deserialize_with = "from_hex")] u8,
+            #[serde(deserialize_with = "from_hex", serialize_with = "to_hex")] u8,
+            #[serde(deserialize_with = "from_hex", serialize_with = "to_hex")] u8,
+            #[serde(deserialize_with = "from_hex", serialize_with = "to_hex")] u8,
         ),
         Rgb8Struct {
-            #[serde(deserialize_with = "from_hex")]
+            #[serde(deserialize_with = "from_hex", serialize_with = "to_hex")]
             r: u8,
-            #[serde(deserialize_with = "from_hex")]
+            #[serde(deserialize_with = "from_hex", serialize_with = "to_hex")]
             g: u8,
-            #[serde(deserialize_with = "from_hex")]
+            #[serde(deserialize_with = "from_hex", serialize_with = "to_hex")]
             b: u8,
         },
         Rgba8Tuple(
-            #[serde(deserialize_with = "from_hex")] u8,
-            #[serde(deserialize_with = "from_hex")] u8,
-            #[serde(deserialize_with = "from_hex")] u8,
-            #[serde(deserialize_with = "from_f32")] f32,
+            #[serde(deserialize_with = "from_hex", serialize_with = "to_hex")] u8,
+            #[serde(deserialize_with = "from_hex", serialize_with = "to_hex")] u8,
+            #[serde(deserialize_with = "from_hex", serialize_with = "to_hex")] u8,
+            #[serde(deserialize_with = "from_f32", serialize_with = "to_f32_str")] f32,
         ),
         Rgba8Struct {
-            #[serde(deserialize_with = "from_hex")]
+            #[serde(deserialize_with = "from_hex", serialize_with = "to_hex")]
             r: u8,
-            #[serde(deserialize_with = "from_hex")]
+            #[serde(deserialize_with = "from_hex", serialize_with = "to_hex")]
             g: u8,
-            #[serde(deserialize_with = "from_hex")]
+            #[serde(deserialize_with = "from_hex", serialize_with = "to_hex")]
             b: u8,
-            #[serde(deserialize_with = "from_f32")]
+            #[serde(deserialize_with = "from_f32", serialize_with = "to_f32_str")]
+            a: f32,
+        },
+        /// An [OKLCH](https://bottosson.github.io/posts/oklab) color: the
+        /// lightness in `0.0..=1.0`, the chroma, and the hue in degrees. The
+        /// alpha can be written as `alpha` or `a`.
+        Oklch {
+            l: f32,
+            c: f32,
+            h: f32,
+            #[serde(default, alias = "a", skip_serializing_if = "Option::is_none")]
+            alpha: Option<f32>,
+        },
+        /// An [OKLab](https://bottosson.github.io/posts/oklab) color: the
+        /// lightness in `0.0..=1.0`, and the green-red and blue-yellow axes.
+        Oklab {
+            l: f32,
             a: f32,
+            b: f32,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            alpha: Option<f32>,
+        },
+        /// An HSL color: the hue in degrees, and the saturation and lightness
+        /// in `0.0..=1.0`. The alpha can be written as `alpha` or `a`.
+        Hsl {
+            h: f32,
+            s: f32,
+            l: f32,
+            #[serde(default, alias = "a", skip_serializing_if = "Option::is_none")]
+            alpha: Option<f32>,
+        },
+        /// An HSV color: the hue in degrees, and the saturation and value in
+        /// `0.0..=1.0`. The alpha can be written as `alpha` or `a`.
+        Hsv {
+            h: f32,
+            s: f32,
+            v: f32,
+            #[serde(default, alias = "a", skip_serializing_if = "Option::is_none")]
+            alpha: Option<f32>,
         },
     }
 
-    impl<'de> Deserialize<'de> for super::Color {
-        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-        where
-            D: Deserializer<'de>,
-        {
-            Ok(Self(match Color::deserialize(deserializer)? {
-                Color::Alias(color) => resolve_color_alias(color).map_err(D::Error::custom)?,
-                Color::RgbTuple(r, g, b) | Color::RgbStruct { r, g, b } => {
+    impl Color {
+        /// Returns the representation of the given color in the configuration file.
+        #[allow(clippy::float_cmp)] // exact comparison is intended
+        pub(super) fn new(color: iced_native::Color) -> Self {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::float_cmp)]
+            fn to_u8(v: f32) -> Option<u8> {
+                let b = (v * 255.0).round();
+                ((0.0..=255.0).contains(&b) && f32::from(b as u8) / 255.0 == v).then_some(b as u8)
+            }
+
+            let iced_native::Color { r, g, b, a } = color;
+            match (to_u8(r), to_u8(g), to_u8(b), to_u8(a)) {
+                (Some(r), Some(g), Some(b), Some(255)) => {
+                    Self::Str(format!("#{r:02X}{g:02X}{b:02X}"))
+                }
+                (Some(r), Some(g), Some(b), Some(a)) => {
+                    Self::Str(format!("#{r:02X}{g:02X}{b:02X}{a:02X}"))
+                }
+                _ if a == 1.0 => Self::RgbTuple(r, g, b),
+                _ => Self::RgbaTuple(r, g, b, a),
+            }
+        }
+    }
+
+    impl Color {
+        /// Converts the input into a color, resolving aliases with `resolve`.
+        fn resolve(
+            &self,
+            resolve: &mut dyn FnMut(&str) -> Result<iced_native::Color, ColorError>,
+        ) -> Result<iced_native::Color, ColorError> {
+            Ok(match *self {
+                Self::Str(ref color) => resolve_str(color, resolve)?,
+                Self::RgbTuple(r, g, b) | Self::RgbStruct { r, g, b } => {
                     iced_native::Color::from_rgb(r, g, b)
                 }
-                Color::RgbaTuple(r, g, b, a) | Color::RgbaStruct { r, g, b, a } => {
+                Self::RgbaTuple(r, g, b, a) | Self::RgbaStruct { r, g, b, a } => {
                     iced_native::Color::from_rgba(r, g, b, a)
                 }
-                Color::RgbaStruct2 { rgb, a } => {
-                    let rgb = resolve_color_alias(rgb).map_err(D::Error::custom)?;
+                Self::RgbaStruct2 { ref rgb, a } => {
+                    let rgb = resolve_str(rgb, resolve)?;
                     iced_native::Color::from_rgba(rgb.r, rgb.g, rgb.b, a)
                 }
-                Color::Rgb8Tuple(r, g, b) | Color::Rgb8Struct { r, g, b } => {
+                Self::Rgb8Tuple(r, g, b) | Self::Rgb8Struct { r, g, b } => {
                     iced_native::Color::from_rgb8(r, g, b)
                 }
-                Color::Rgba8Tuple(r, g, b, a) | Color::Rgba8Struct { r, g, b, a } => {
+                Self::Rgba8Tuple(r, g, b, a) | Self::Rgba8Struct { r, g, b, a } => {
                     iced_native::Color::from_rgba8(r, g, b, a)
                 }
-            }))
+                Self::Oklch { l, c, h, alpha } => with_alpha(super::from_oklch(l, c, h), alpha),
+                Self::Oklab { l, a, b, alpha } => with_alpha(super::from_oklab(l, a, b), alpha),
+                Self::Hsl { h, s, l, alpha } => with_alpha(super::from_hsl(h, s, l), alpha),
+                Self::Hsv { h, s, v, alpha } => with_alpha(super::from_hsv(h, s, v), alpha),
+            })
         }
     }
 
-    pub(super) fn resolve_color_alias(mut key: String) -> Result<iced_native::Color, String> {
-        key.make_ascii_lowercase();
-        if let Some(color) = super::DEFAULT_COLOR_ALIASES.get(&*key).copied() {
-            return Ok(color);
+    fn with_alpha(color: iced_native::Color, alpha: Option<f32>) -> iced_native::Color {
+        iced_native::Color { a: alpha.map_or(1.0, |a| a.clamp(0.0, 1.0)), ..color }
+    }
+
+    /// Converts a hex color, a color function, or the name of a color alias
+    /// into a color.
+    ///
+    /// Strings starting with `#` are always hex colors, never aliases.
+    pub(super) fn resolve_str(
+        s: &str,
+        resolve: &mut dyn FnMut(&str) -> Result<iced_native::Color, ColorError>,
+    ) -> Result<iced_native::Color, ColorError> {
+        if s.starts_with('#') {
+            return Ok(parse_hex_color(s)?);
+        }
+        match super::css::Function::parse(s) {
+            Some(f) => {
+                let f = f?;
+                super::css::parse_color(&f)
+                    .map(|color| Ok(color?))
+                    .or_else(|| super::function::apply(&f, resolve))
+                    .unwrap_or_else(|| Err(format!("unknown color function `{}`", f.name).into()))
+            }
+            None => resolve(s),
+        }
+    }
+
+    /// Parses a hex color: `#RGB`, `#RGBA`, `#RRGGBB`, or `#RRGGBBAA`.
+    pub(super) fn parse_hex_color(s: &str) -> Result<iced_native::Color, String> {
+        let digits = &s[1..];
+        if let Some(c) = digits.chars().find(|c| !c.is_ascii_hexdigit()) {
+            return Err(format!("invalid hex color `{s}`: `{c}` is not a hexadecimal digit"));
+        }
+        let byte = |i: usize, n: usize| {
+            let v = u8::from_str_radix(&digits[i * n..(i + 1) * n], 16).unwrap();
+            if n == 1 {
+                v * 0x11
+            } else {
+                v
+            }
+        };
+        let (n, has_alpha) = match digits.len() {
+            3 => (1, false),
+            4 => (1, true),
+            6 => (2, false),
+            8 => (2, true),
+            len => {
+                return Err(format!(
+                    "invalid hex color `{s}`: expected 3, 4, 6, or 8 hexadecimal digits, found {len}"
+                ));
+            }
+        };
+        let a = if has_alpha { f32::from(byte(3, n)) / 255.0 } else { 1.0 };
+        Ok(iced_native::Color::from_rgba8(byte(0, n), byte(1, n), byte(2, n), a))
+    }
+
+    impl<'de> Deserialize<'de> for super::Color {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let input = Color::deserialize(deserializer)?;
+            let value = input
+                .resolve(&mut resolve_color_alias)
+                .map_err(|e| D::Error::custom(Payload(&ParseError::from_color(e, vec![]))))?;
+            Ok(Self { value, input: Box::new(input) })
         }
+    }
+
+    impl Serialize for super::Color {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match *self.input {
+                // Keep aliases and CSS color functions as written.
+                Color::Str(_) | Color::RgbaStruct2 { .. } => self.input.serialize(serializer),
+                _ => Color::new(self.value).serialize(serializer),
+            }
+        }
+    }
+
+    pub(super) fn resolve_color_alias(key: &str) -> Result<iced_native::Color, ColorError> {
+        let key = key.to_ascii_lowercase();
         if let Some(color) = super::COLOR_ALIASES.with(|map| map.borrow().get(&*key).copied()) {
             return Ok(color);
         }
-        Err(format!("cannot found color alias `{key}`"))
+        if let Some(color) = super::DEFAULT_COLOR_ALIASES.get(&*key).copied() {
+            return Ok(color);
+        }
+        let suggestion = super::COLOR_ALIASES.with(|map| {
+            crate::suggest::did_you_mean(
+                &key,
+                map.borrow().keys().chain(super::DEFAULT_COLOR_ALIASES.keys()),
+            )
+        });
+        Err(ColorError::unknown_alias(&key, suggestion))
     }
 
     impl<'de> Deserialize<'de> for super::ColorAliases {
@@ -195,19 +447,138 @@ mod de {
         where
             D: Deserializer<'de>,
         {
-            if let Some(map) = <Option<HashMap<_, super::Color>>>::deserialize(deserializer)? {
-                let map: super::ColorMap = map.into_iter().collect();
-                super::COLOR_ALIASES.with(|a| *a.borrow_mut() = map.clone());
-                Ok(Self(map))
-            } else {
-                Ok(Self::default())
+            match <Option<BTreeMap<String, Color>>>::deserialize(deserializer)? {
+                Some(inputs) => match super::ColorAliases::resolve(inputs) {
+                    (this, errors) if errors.is_empty() => Ok(this),
+                    (_, mut errors) => Err(D::Error::custom(errors.swap_remove(0).1)),
+                },
+                None => Ok(Self::default()),
             }
         }
     }
+
+    impl super::ColorAliases {
+        /// Resolves the color aliases in the `[color]` table, and returns them
+        /// along with the errors in each alias.
+        ///
+        /// Invalid aliases are resolved to black so that the values that refer
+        /// to them can still be checked.
+        pub(crate) fn from_value(value: &toml::Value) -> (Self, Vec<ParseError>) {
+            let key = |name: String| vec!["color".to_owned(), name];
+            let table = match crate::value::from_value::<BTreeMap<String, toml::Value>>(value) {
+                Ok(table) => table,
+                Err(e) => return (Self::default(), vec![e.in_key("color")]),
+            };
+            let mut errors = vec![];
+            let inputs = table
+                .into_iter()
+                .map(|(name, value)| {
+                    let input = crate::value::from_value(&value).unwrap_or_else(|e| {
+                        errors.push(e.in_key(&name).in_key("color"));
+                        Color::Str("BLACK".to_owned())
+                    });
+                    (name, input)
+                })
+                .collect();
+            let (this, unresolved) = Self::resolve(inputs);
+            errors.extend(
+                unresolved.into_iter().map(|(name, e)| ParseError::from_color(e, key(name))),
+            );
+            (this, errors)
+        }
+
+        /// Resolves the given color aliases, and returns them along with the
+        /// names of the invalid aliases and the errors in them.
+        fn resolve(inputs: BTreeMap<String, Color>) -> (Self, Vec<(String, ColorError)>) {
+            let mut resolver = AliasResolver {
+                inputs: inputs.iter().map(|(k, v)| (k.to_ascii_lowercase(), (&**k, v))).collect(),
+                resolved: super::ColorMap::with_capacity(inputs.len()),
+                stack: vec![],
+                errors: vec![],
+            };
+            for name in inputs.keys() {
+                resolver.resolve(name).unwrap();
+            }
+            let map = resolver.resolved;
+            let errors = resolver.errors.into_iter().map(|(k, e)| (k.to_owned(), e)).collect();
+            super::COLOR_ALIASES.with(|a| *a.borrow_mut() = map.clone());
+            let colors = inputs
+                .into_iter()
+                .map(|(k, input)| {
+                    let value = map[&*k];
+                    (k, super::Color { value, input: Box::new(input) })
+                })
+                .collect();
+            (Self { map, colors }, errors)
+        }
+    }
+
+    /// Resolves color aliases defined in the `[color]` table.
+    ///
+    /// Aliases can refer to each other regardless of the order in which they
+    /// are defined, and take precedence over built-in aliases.
+    struct AliasResolver<'a> {
+        /// The aliases as written, keyed by lowercase name.
+        inputs: HashMap<String, (&'a str, &'a Color)>,
+        resolved: super::ColorMap,
+        /// The aliases currently being resolved, used for cycle detection.
+        stack: Vec<&'a str>,
+        /// The invalid aliases and the errors in them.
+        errors: Vec<(&'a str, ColorError)>,
+    }
+
+    impl AliasResolver<'_> {
+        fn resolve(&mut self, key: &str) -> Result<iced_native::Color, ColorError> {
+            let lower = key.to_ascii_lowercase();
+            if let Some(color) = self.resolved.get(&*lower).copied() {
+                return Ok(color);
+            }
+            let Some(&(name, input)) = self.inputs.get(&lower) else {
+                if let Some(color) = super::DEFAULT_COLOR_ALIASES.get(&*lower) {
+                    return Ok(*color);
+                }
+                let names = self.inputs.keys().map(String::as_str);
+                let suggestion = crate::suggest::did_you_mean(
+                    &lower,
+                    names.chain(super::DEFAULT_COLOR_ALIASES.keys()),
+                );
+                return Err(ColorError::unknown_alias(&lower, suggestion));
+            };
+            if let Some(pos) = self.stack.iter().position(|k| k.eq_ignore_ascii_case(name)) {
+                let cycle: Vec<_> =
+                    self.stack[pos..].iter().chain(Some(&name)).map(|k| format!("`{k}`")).collect();
+                return Err(format!("cyclic color alias: {}", cycle.join(" -> ")).into());
+            }
+            self.stack.push(name);
+            let color = input.resolve(&mut |k| self.resolve(k));
+            self.stack.pop();
+            // Record the error in the alias itself, not in the aliases that
+            // refer to it.
+            let color = color.unwrap_or_else(|e| {
+                self.errors.push((name, e));
+                iced_native::Color::BLACK
+            });
+            self.resolved.insert(name, color);
+            Ok(color)
+        }
+    }
+
+    impl Serialize for super::ColorAliases {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.colors.serialize(serializer)
+        }
+    }
 }
 
 #[cfg(feature = "schema")]
 mod schema {
+    use schemars::schema::{
+        InstanceType, Metadata, SchemaObject, StringValidation, SubschemaValidation,
+    };
+
     use super::*;
 
     impl JsonSchema for Color {
@@ -220,14 +591,58 @@ mod schema {
         }
     }
 
-    impl Serialize for Color {
-        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-        where
-            S: Serializer,
-        {
-            let _ = serializer;
-            unimplemented!()
+    pub(super) fn color_str(_: &mut SchemaGenerator) -> Schema {
+        let hex = SchemaObject {
+            metadata: Some(Box::new(Metadata {
+                description: Some(
+                    "A hex color: `#RGB`, `#RGBA`, `#RRGGBB`, or `#RRGGBBAA`.".into(),
+                ),
+                ..Metadata::default()
+            })),
+            instance_type: Some(InstanceType::String.into()),
+            string: Some(Box::new(StringValidation {
+                pattern: Some("^#([0-9a-fA-F]{3,4}|[0-9a-fA-F]{6}|[0-9a-fA-F]{8})$".into()),
+                ..StringValidation::default()
+            })),
+            ..SchemaObject::default()
+        };
+        let function = SchemaObject {
+            metadata: Some(Box::new(Metadata {
+                description: Some(
+                    "A CSS color function (`rgb()`, `rgba()`, `hsl()`, `hsla()`, or `hwb()`), \
+                     or a color manipulation function (`lighten()`, `darken()`, \
+                     `saturate()`, `desaturate()`, `mix()`, `alpha()`, or `complement()`)."
+                        .into(),
+                ),
+                ..Metadata::default()
+            })),
+            instance_type: Some(InstanceType::String.into()),
+            string: Some(Box::new(StringValidation {
+                pattern: Some(r"^\s*[0-9A-Za-z_]+\s*\(.*\)\s*$".into()),
+                ..StringValidation::default()
+            })),
+            ..SchemaObject::default()
+        };
+        let alias = SchemaObject {
+            metadata: Some(Box::new(Metadata {
+                description: Some("The name of a color alias.".into()),
+                ..Metadata::default()
+            })),
+            instance_type: Some(InstanceType::String.into()),
+            string: Some(Box::new(StringValidation {
+                pattern: Some("^[^#(]*$".into()),
+                ..StringValidation::default()
+            })),
+            ..SchemaObject::default()
+        };
+        SchemaObject {
+            subschemas: Some(Box::new(SubschemaValidation {
+                any_of: Some(vec![hex.into(), function.into(), alias.into()]),
+                ..SubschemaValidation::default()
+            })),
+            ..SchemaObject::default()
         }
+        .into()
     }
 
     impl JsonSchema for ColorAliases {
@@ -239,14 +654,4 @@ mod schema {
             <HashMap<String, Color>>::json_schema(gen)
         }
     }
-
-    impl Serialize for ColorAliases {
-        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-        where
-            S: Serializer,
-        {
-            let _ = serializer;
-            unimplemented!()
-        }
-    }
 }
@@ -0,0 +1,236 @@
+//! Conversions between color spaces.
+
+#![allow(clippy::many_single_char_names)] // conventional names in color math
+
+/// Converts HSL into a color.
+///
+/// `h` is the hue in degrees, and `s` and `l` are the saturation and lightness
+/// in `0.0..=1.0`. Out-of-range saturation and lightness are clamped.
+pub fn from_hsl(h: f32, s: f32, l: f32) -> iced_native::Color {
+    let [r, g, b] = hsl_to_rgb(h.rem_euclid(360.0), s.clamp(0.0, 1.0), l.clamp(0.0, 1.0));
+    iced_native::Color::from_rgb(r, g, b)
+}
+
+/// Converts a color into HSL: the hue in degrees, and the saturation and
+/// lightness in `0.0..=1.0`.
+pub fn to_hsl(color: iced_native::Color) -> [f32; 3] {
+    rgb_to_hsl(color.r, color.g, color.b)
+}
+
+/// Converts HSV into a color.
+///
+/// `h` is the hue in degrees, and `s` and `v` are the saturation and value in
+/// `0.0..=1.0`. Out-of-range saturation and value are clamped.
+pub fn from_hsv(h: f32, s: f32, v: f32) -> iced_native::Color {
+    let (h, s, v) = (h.rem_euclid(360.0), s.clamp(0.0, 1.0), v.clamp(0.0, 1.0));
+    let f = |n: f32| {
+        let k = (n + h / 60.0) % 6.0;
+        v - v * s * k.min(4.0 - k).clamp(0.0, 1.0)
+    };
+    iced_native::Color::from_rgb(f(5.0), f(3.0), f(1.0))
+}
+
+/// Converts a color into HSV: the hue in degrees, and the saturation and value
+/// in `0.0..=1.0`.
+pub fn to_hsv(color: iced_native::Color) -> [f32; 3] {
+    let iced_native::Color { r, g, b, .. } = color;
+    let [h, ..] = rgb_to_hsl(r, g, b);
+    let v = r.max(g).max(b);
+    let d = v - r.min(g).min(b);
+    [h, if v > 0.0 { d / v } else { 0.0 }, v]
+}
+
+/// Converts [OKLab] into a color.
+///
+/// `l` is the lightness in `0.0..=1.0`, and `a` and `b` are the green-red and
+/// blue-yellow axes (usually in `-0.4..=0.4`). Colors outside the sRGB gamut
+/// are mapped into the gamut by reducing the chroma, as specified in
+/// [CSS Color Module Level 4].
+///
+/// [OKLab]: https://bottosson.github.io/posts/oklab
+/// [CSS Color Module Level 4]: https://www.w3.org/TR/css-color-4/#gamut-mapping
+pub fn from_oklab(l: f32, a: f32, b: f32) -> iced_native::Color {
+    let [l, c, h] = lab_to_lch([l, a, b]);
+    from_oklch(l, c, h)
+}
+
+/// Converts a color into [OKLab]: the lightness, and the green-red and
+/// blue-yellow axes.
+///
+/// [OKLab]: https://bottosson.github.io/posts/oklab
+pub fn to_oklab(color: iced_native::Color) -> [f32; 3] {
+    linear_to_oklab([color.r, color.g, color.b].map(to_linear))
+}
+
+/// Converts [OKLCH] into a color.
+///
+/// `l` is the lightness in `0.0..=1.0`, `c` is the chroma (usually in
+/// `0.0..=0.4`), and `h` is the hue in degrees. Colors outside the sRGB gamut
+/// are mapped into the gamut by reducing the chroma, as specified in
+/// [CSS Color Module Level 4].
+///
+/// [OKLCH]: https://bottosson.github.io/posts/oklab
+/// [CSS Color Module Level 4]: https://www.w3.org/TR/css-color-4/#gamut-mapping
+pub fn from_oklch(l: f32, c: f32, h: f32) -> iced_native::Color {
+    let [r, g, b] = gamut_map([l, c.max(0.0), h]).map(from_linear);
+    iced_native::Color::from_rgb(r, g, b)
+}
+
+/// Converts a color into [OKLCH]: the lightness, the chroma, and the hue in
+/// degrees.
+///
+/// [OKLCH]: https://bottosson.github.io/posts/oklab
+pub fn to_oklch(color: iced_native::Color) -> [f32; 3] {
+    let [l, a, b] = to_oklab(color);
+    let c = a.hypot(b);
+    // The hue of an achromatic color is meaningless; use 0 instead of noise.
+    let h = if c < 1e-4 { 0.0 } else { b.atan2(a).to_degrees().rem_euclid(360.0) };
+    [l, c, h]
+}
+
+/// Converts HSL into sRGB. `h` is in degrees, `s` and `l` are in `0.0..=1.0`.
+pub(crate) fn hsl_to_rgb(h: f32, s: f32, l: f32) -> [f32; 3] {
+    let f = |n: f32| {
+        let k = (n + h / 30.0) % 12.0;
+        let a = s * l.min(1.0 - l);
+        l - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+    [f(0.0), f(8.0), f(4.0)]
+}
+
+/// Converts sRGB into HSL. The returned hue is in degrees, and saturation and
+/// lightness are in `0.0..=1.0`.
+#[allow(clippy::float_cmp)] // comparing with the inputs themselves
+#[allow(clippy::manual_midpoint)] // f32::midpoint is not available before Rust 1.85
+pub(crate) fn rgb_to_hsl(r: f32, g: f32, b: f32) -> [f32; 3] {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let d = max - min;
+    let l = (max + min) / 2.0;
+    if d == 0.0 {
+        return [0.0, 0.0, l];
+    }
+    let s = if l == 0.0 || l == 1.0 { 0.0 } else { d / (1.0 - (2.0 * l - 1.0).abs()) };
+    let h = if max == r {
+        ((g - b) / d).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / d + 2.0
+    } else {
+        (r - g) / d + 4.0
+    };
+    [h * 60.0, s, l]
+}
+
+/// Converts HWB into sRGB. `h` is in degrees, `w` and `b` are in `0.0..=1.0`.
+pub(crate) fn hwb_to_rgb(h: f32, w: f32, b: f32) -> [f32; 3] {
+    if w + b >= 1.0 {
+        let gray = w / (w + b);
+        return [gray; 3];
+    }
+    hsl_to_rgb(h, 1.0, 0.5).map(|c| c * (1.0 - w - b) + w)
+}
+
+fn to_linear(c: f32) -> f32 {
+    if c <= 0.040_45 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn from_linear(c: f32) -> f32 {
+    if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+// https://bottosson.github.io/posts/oklab/#converting-from-linear-srgb-to-oklab
+#[allow(clippy::excessive_precision, clippy::unreadable_literal)]
+fn linear_to_oklab([r, g, b]: [f32; 3]) -> [f32; 3] {
+    let l = (0.4122214708 * r + 0.5363262452 * g + 0.0514459929 * b).cbrt();
+    let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+    let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+    [
+        0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+        1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+        0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+    ]
+}
+
+#[allow(clippy::excessive_precision, clippy::unreadable_literal)]
+fn oklab_to_linear([l, a, b]: [f32; 3]) -> [f32; 3] {
+    let l_ = (l + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+    let m_ = (l - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+    let s_ = (l - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+    [
+        4.0767416621 * l_ - 3.3077115913 * m_ + 0.2309699292 * s_,
+        -1.2684380046 * l_ + 2.6097574011 * m_ - 0.3413193965 * s_,
+        -0.0041960863 * l_ - 0.7034186147 * m_ + 1.7076147010 * s_,
+    ]
+}
+
+fn lab_to_lch([l, a, b]: [f32; 3]) -> [f32; 3] {
+    [l, a.hypot(b), b.atan2(a).to_degrees()]
+}
+
+fn lch_to_lab([l, c, h]: [f32; 3]) -> [f32; 3] {
+    let (sin, cos) = h.to_radians().sin_cos();
+    [l, c * cos, c * sin]
+}
+
+/// Maps an OKLCH color into the sRGB gamut and returns it in linear sRGB.
+///
+/// See <https://www.w3.org/TR/css-color-4/#binsearch>.
+#[allow(clippy::manual_midpoint)] // f32::midpoint is not available before Rust 1.85
+fn gamut_map([l, c, h]: [f32; 3]) -> [f32; 3] {
+    /// The just noticeable difference.
+    const JND: f32 = 0.02;
+    const EPSILON: f32 = 0.0001;
+
+    let in_gamut = |rgb: [f32; 3]| rgb.iter().all(|c| (-EPSILON..=1.0 + EPSILON).contains(c));
+    let clip = |rgb: [f32; 3]| rgb.map(|c| c.clamp(0.0, 1.0));
+    let delta_e = |a: [f32; 3], b: [f32; 3]| {
+        a.iter().zip(b).map(|(a, b)| (a - b).powi(2)).sum::<f32>().sqrt()
+    };
+    let to_linear = |lch: [f32; 3]| oklab_to_linear(lch_to_lab(lch));
+
+    if l >= 1.0 {
+        return [1.0; 3];
+    }
+    if l <= 0.0 {
+        return [0.0; 3];
+    }
+    let origin = to_linear([l, c, h]);
+    if in_gamut(origin) {
+        return clip(origin);
+    }
+
+    let mut clipped = clip(origin);
+    if delta_e(linear_to_oklab(clipped), lch_to_lab([l, c, h])) < JND {
+        return clipped;
+    }
+    let (mut min, mut max) = (0.0, c);
+    let mut min_in_gamut = true;
+    while max - min > EPSILON {
+        let chroma = (min + max) / 2.0;
+        let current = to_linear([l, chroma, h]);
+        if min_in_gamut && in_gamut(current) {
+            min = chroma;
+            continue;
+        }
+        clipped = clip(current);
+        let e = delta_e(linear_to_oklab(clipped), lch_to_lab([l, chroma, h]));
+        if e < JND {
+            if JND - e < EPSILON {
+                break;
+            }
+            min_in_gamut = false;
+            min = chroma;
+        } else {
+            max = chroma;
+        }
+    }
+    clipped
+}
@@ -0,0 +1,200 @@
+//! Parsing of CSS color functions, e.g., `rgb(111 255 233 / 50%)`.
+//!
+//! See <https://www.w3.org/TR/css-color-4> for the syntax.
+
+#![allow(clippy::many_single_char_names)] // conventional names in color math
+
+use super::convert::{hsl_to_rgb, hwb_to_rgb};
+
+/// A function call, e.g., `hsl(120deg 50% 50%)`.
+pub(super) struct Function<'a> {
+    /// The function call as written.
+    pub(super) src: &'a str,
+    pub(super) name: &'a str,
+    /// The arguments, separated by commas or spaces.
+    pub(super) args: Vec<&'a str>,
+    /// The argument after `/`, if any.
+    pub(super) alpha: Option<&'a str>,
+    /// Whether the arguments are separated by commas (the legacy syntax).
+    pub(super) legacy: bool,
+}
+
+impl<'a> Function<'a> {
+    /// Parses a function call. Returns `None` if `s` is not a function call.
+    pub(super) fn parse(s: &'a str) -> Option<Result<Self, String>> {
+        let s = s.trim();
+        let (name, rest) = s.split_once('(')?;
+        let name = name.trim_end();
+        if name.is_empty() || !name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_') {
+            return None;
+        }
+        let Some(inner) = rest.strip_suffix(')') else {
+            return Some(Err(format!("invalid color `{s}`: expected `)` at the end")));
+        };
+        Some(Self::parse_args(s, name, inner).map_err(|e| format!("invalid color `{s}`: {e}")))
+    }
+
+    fn parse_args(src: &'a str, name: &'a str, inner: &'a str) -> Result<Self, String> {
+        let parts = split(inner, |c| c == ',')?;
+        if parts.len() > 1 {
+            let args: Vec<_> = parts.into_iter().map(str::trim).collect();
+            if args.iter().any(|arg| arg.is_empty()) {
+                return Err("empty argument".into());
+            }
+            if args.iter().any(|arg| arg.contains('/')) {
+                return Err("`/` cannot be used with comma-separated arguments".into());
+            }
+            return Ok(Self { src, name, args, alpha: None, legacy: true });
+        }
+
+        let mut parts = split(inner, |c| c == '/')?.into_iter();
+        let args = words(parts.next().unwrap())?;
+        let alpha = match parts.next().map(words).transpose()?.as_deref() {
+            None => None,
+            Some(&[alpha]) => Some(alpha),
+            Some(_) => return Err("expected exactly one argument after `/`".into()),
+        };
+        if parts.next().is_some() {
+            return Err("unexpected `/`".into());
+        }
+        Ok(Self { src, name, args, alpha, legacy: false })
+    }
+}
+
+/// Splits `s` at the characters that match `pat` and are not in parentheses.
+fn split(s: &str, pat: impl Fn(char) -> bool) -> Result<Vec<&str>, String> {
+    let mut parts = vec![];
+    let mut depth = 0_u32;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.checked_sub(1).ok_or("unbalanced parentheses")?,
+            c if depth == 0 && pat(c) => {
+                parts.push(&s[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return Err("unbalanced parentheses".into());
+    }
+    parts.push(&s[start..]);
+    Ok(parts)
+}
+
+/// Splits `s` at whitespace that is not in parentheses.
+fn words(s: &str) -> Result<Vec<&str>, String> {
+    Ok(split(s, char::is_whitespace)?.into_iter().filter(|s| !s.is_empty()).collect())
+}
+
+/// Parses a CSS color function (`rgb()`, `rgba()`, `hsl()`, `hsla()`, or `hwb()`).
+///
+/// Returns `None` if `f` is not one of these functions.
+pub(super) fn parse_color(f: &Function<'_>) -> Option<Result<iced_native::Color, String>> {
+    let parse = match &*f.name.to_ascii_lowercase() {
+        "rgb" | "rgba" => rgb,
+        "hsl" | "hsla" => hsl,
+        "hwb" => hwb,
+        _ => return None,
+    };
+    Some(parse(f).map_err(|e| format!("invalid color `{}`: {e}", f.src)))
+}
+
+fn args<'a>(f: &Function<'a>, legacy: bool) -> Result<([&'a str; 3], Option<&'a str>), String> {
+    if f.legacy && !legacy {
+        return Err("arguments must be separated by spaces".into());
+    }
+    match (&*f.args, f.alpha) {
+        (&[a, b, c], alpha) => Ok(([a, b, c], alpha)),
+        (&[a, b, c, alpha], None) if f.legacy => Ok(([a, b, c], Some(alpha))),
+        (args, alpha) => Err(format!(
+            "expected 3 arguments{}, found {}",
+            if f.legacy { " and an optional alpha" } else { " and an optional `/ alpha`" },
+            args.len() + usize::from(alpha.is_some())
+        )),
+    }
+}
+
+fn rgb(f: &Function<'_>) -> Result<iced_native::Color, String> {
+    let ([r, g, b], alpha) = args(f, true)?;
+    let channel = |s: &str| {
+        Ok::<_, String>(match percentage(s)? {
+            Some(p) => p,
+            None => number(s)? / 255.0,
+        })
+    };
+    Ok(iced_native::Color::from_rgba(
+        clamp(channel(r)?),
+        clamp(channel(g)?),
+        clamp(channel(b)?),
+        alpha_value(alpha)?,
+    ))
+}
+
+fn hsl(f: &Function<'_>) -> Result<iced_native::Color, String> {
+    let ([h, s, l], alpha) = args(f, true)?;
+    let [r, g, b] = hsl_to_rgb(hue(h)?, clamp(ratio(s)?), clamp(ratio(l)?));
+    Ok(iced_native::Color::from_rgba(r, g, b, alpha_value(alpha)?))
+}
+
+fn hwb(f: &Function<'_>) -> Result<iced_native::Color, String> {
+    let ([h, w, b], alpha) = args(f, false)?;
+    let [r, g, b] = hwb_to_rgb(hue(h)?, clamp(ratio(w)?), clamp(ratio(b)?));
+    Ok(iced_native::Color::from_rgba(r, g, b, alpha_value(alpha)?))
+}
+
+pub(super) fn number(s: &str) -> Result<f32, String> {
+    match s.parse::<f32>() {
+        Ok(v) if v.is_finite() => Ok(v),
+        _ => Err(format!("`{s}` is not a number")),
+    }
+}
+
+/// Parses a percentage into `0.0..=1.0` scale. Returns `None` if `s` does not end with `%`.
+pub(super) fn percentage(s: &str) -> Result<Option<f32>, String> {
+    match s.strip_suffix('%') {
+        Some(v) => Ok(Some(number(v).map_err(|_| format!("`{s}` is not a percentage"))? / 100.0)),
+        None => Ok(None),
+    }
+}
+
+/// Parses a percentage, or a number in `0.0..=100.0` scale, into `0.0..=1.0` scale.
+pub(super) fn ratio(s: &str) -> Result<f32, String> {
+    match percentage(s)? {
+        Some(v) => Ok(v),
+        None => Ok(number(s)? / 100.0),
+    }
+}
+
+/// Parses an alpha value: a number in `0.0..=1.0` or a percentage.
+pub(super) fn alpha_value(s: Option<&str>) -> Result<f32, String> {
+    let Some(s) = s else { return Ok(1.0) };
+    Ok(clamp(match percentage(s)? {
+        Some(v) => v,
+        None => number(s)?,
+    }))
+}
+
+/// Parses a hue into degrees: a number or an angle (`deg`, `rad`, `grad`, or `turn`).
+pub(super) fn hue(s: &str) -> Result<f32, String> {
+    let lower = s.to_ascii_lowercase();
+    let (v, scale) = if let Some(v) = lower.strip_suffix("deg") {
+        (v, 1.0)
+    } else if let Some(v) = lower.strip_suffix("grad") {
+        (v, 0.9)
+    } else if let Some(v) = lower.strip_suffix("rad") {
+        (v, 180.0 / std::f32::consts::PI)
+    } else if let Some(v) = lower.strip_suffix("turn") {
+        (v, 360.0)
+    } else {
+        (&*lower, 1.0)
+    };
+    let v = number(v).map_err(|_| format!("`{s}` is not an angle"))?;
+    Ok((v * scale).rem_euclid(360.0))
+}
+
+pub(super) fn clamp(v: f32) -> f32 {
+    v.clamp(0.0, 1.0)
+}
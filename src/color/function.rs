@@ -0,0 +1,129 @@
+//! Color manipulation functions, e.g., `lighten(ACCENT, 10%)`.
+//!
+//! The color arguments can be anything that can be written as a color string:
+//! hex colors, color aliases, CSS color functions, or other manipulation
+//! functions.
+
+#![allow(clippy::many_single_char_names)] // conventional names in color math
+
+use super::{
+    convert::{hsl_to_rgb, rgb_to_hsl},
+    css::{alpha_value, clamp, number, percentage, Function},
+    de::resolve_str,
+    ColorError,
+};
+
+type Resolve<'a> = dyn FnMut(&str) -> Result<iced_native::Color, ColorError> + 'a;
+
+/// Applies a color manipulation function (`lighten()`, `darken()`,
+/// `saturate()`, `desaturate()`, `mix()`, `alpha()`, or `complement()`).
+///
+/// Returns `None` if `f` is not one of these functions.
+pub(super) fn apply(
+    f: &Function<'_>,
+    resolve: &mut Resolve<'_>,
+) -> Option<Result<iced_native::Color, ColorError>> {
+    let apply = match &*f.name.to_ascii_lowercase() {
+        "lighten" => lighten,
+        "darken" => darken,
+        "saturate" => saturate,
+        "desaturate" => desaturate,
+        "mix" => mix,
+        "alpha" => alpha,
+        "complement" => complement,
+        _ => return None,
+    };
+    Some(apply(f, resolve).map_err(|e| e.context(format!("invalid color `{}`", f.src))))
+}
+
+/// Returns the arguments, which must be `min..=max` comma-separated values.
+fn args<'a>(f: &'a Function<'_>, min: usize, max: usize) -> Result<&'a [&'a str], String> {
+    if f.alpha.is_some() || (f.args.len() > 1 && !f.legacy) {
+        return Err("arguments must be separated by commas".into());
+    }
+    let n = f.args.len();
+    if n < min || n > max {
+        let expected = if min == max { min.to_string() } else { format!("{min} or {max}") };
+        let s = if max == 1 { "" } else { "s" };
+        return Err(format!("expected {expected} argument{s}, found {n}"));
+    }
+    Ok(&f.args)
+}
+
+/// Parses an amount: a number in `0.0..=1.0` or a percentage.
+fn amount(s: &str) -> Result<f32, String> {
+    match percentage(s)? {
+        Some(v) => Ok(v),
+        None => number(s),
+    }
+}
+
+/// Adjusts the HSL representation of a color.
+fn map_hsl(
+    color: iced_native::Color,
+    map: impl FnOnce([f32; 3]) -> [f32; 3],
+) -> iced_native::Color {
+    let [h, s, l] = map(rgb_to_hsl(color.r, color.g, color.b));
+    let [r, g, b] = hsl_to_rgb(h, clamp(s), clamp(l));
+    iced_native::Color::from_rgba(r, g, b, color.a)
+}
+
+fn adjust(
+    f: &Function<'_>,
+    resolve: &mut Resolve<'_>,
+    g: impl FnOnce([f32; 3], f32) -> [f32; 3],
+) -> Result<iced_native::Color, ColorError> {
+    let &[color, amt] = args(f, 2, 2)? else { unreachable!() };
+    let color = resolve_str(color, resolve)?;
+    let amt = amount(amt)?;
+    Ok(map_hsl(color, |hsl| g(hsl, amt)))
+}
+
+fn lighten(f: &Function<'_>, resolve: &mut Resolve<'_>) -> Result<iced_native::Color, ColorError> {
+    adjust(f, resolve, |[h, s, l], amt| [h, s, l + amt])
+}
+
+fn darken(f: &Function<'_>, resolve: &mut Resolve<'_>) -> Result<iced_native::Color, ColorError> {
+    adjust(f, resolve, |[h, s, l], amt| [h, s, l - amt])
+}
+
+fn saturate(f: &Function<'_>, resolve: &mut Resolve<'_>) -> Result<iced_native::Color, ColorError> {
+    adjust(f, resolve, |[h, s, l], amt| [h, s + amt, l])
+}
+
+fn desaturate(
+    f: &Function<'_>,
+    resolve: &mut Resolve<'_>,
+) -> Result<iced_native::Color, ColorError> {
+    adjust(f, resolve, |[h, s, l], amt| [h, s - amt, l])
+}
+
+/// `mix(a, b, weight)`: `weight` is the proportion of `a` (defaults to 50%).
+fn mix(f: &Function<'_>, resolve: &mut Resolve<'_>) -> Result<iced_native::Color, ColorError> {
+    let args = args(f, 2, 3)?;
+    let a = resolve_str(args[0], resolve)?;
+    let b = resolve_str(args[1], resolve)?;
+    let w = args.get(2).map_or(Ok(0.5), |w| amount(w))?;
+    if !(0.0..=1.0).contains(&w) {
+        return Err(format!("weight `{}` is out of range", args[2]).into());
+    }
+    let mix = |a: f32, b: f32| a * w + b * (1.0 - w);
+    Ok(iced_native::Color::from_rgba(mix(a.r, b.r), mix(a.g, b.g), mix(a.b, b.b), mix(a.a, b.a)))
+}
+
+/// `alpha(color, alpha)`: replaces the alpha channel.
+fn alpha(f: &Function<'_>, resolve: &mut Resolve<'_>) -> Result<iced_native::Color, ColorError> {
+    let &[color, a] = args(f, 2, 2)? else { unreachable!() };
+    let color = resolve_str(color, resolve)?;
+    Ok(iced_native::Color { a: alpha_value(Some(a))?, ..color })
+}
+
+/// `complement(color)`: rotates the hue by 180 degrees.
+fn complement(
+    f: &Function<'_>,
+    resolve: &mut Resolve<'_>,
+) -> Result<iced_native::Color, ColorError> {
+    let &[color] = args(f, 1, 1)? else { unreachable!() };
+    let color = resolve_str(color, resolve)?;
+    Ok(map_hsl(color, |[h, s, l]| [(h + 180.0) % 360.0, s, l]))
+}
@@ -1,8 +1,8 @@
 use crate::import::*;
 
-#[derive(Debug, Clone, Copy, Deserialize)]
-#[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
-#[serde(rename_all = "snake_case")]
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(remote = "Self", rename_all = "snake_case")]
 pub(crate) enum Length {
     Fill,
     FillPortion(u16),
@@ -10,6 +10,42 @@ pub(crate) enum Length {
     Units(u16),
 }
 
+impl<'de> DeserializeEnum<'de> for Length {
+    fn deserialize_derived<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Length {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_enum(deserializer)
+    }
+}
+
+// toml cannot serialize newtype variants, so write them as single-key tables.
+impl Serialize for Length {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (key, value) = match *self {
+            Self::Fill => return serializer.serialize_str("fill"),
+            Self::Shrink => return serializer.serialize_str("shrink"),
+            Self::FillPortion(factor) => ("fill_portion", factor),
+            Self::Units(units) => ("units", units),
+        };
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(key, &value)?;
+        map.end()
+    }
+}
+
 impl From<Length> for iced_native::Length {
     fn from(length: Length) -> Self {
         match length {
@@ -21,8 +57,19 @@ impl From<Length> for iced_native::Length {
     }
 }
 
-#[derive(Debug, Clone, Copy, Deserialize)]
-#[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+impl ToValue for iced_native::Length {
+    fn to_value(self) -> toml::Value {
+        serialize(match self {
+            Self::Fill => Length::Fill,
+            Self::FillPortion(factor) => Length::FillPortion(factor),
+            Self::Shrink => Length::Shrink,
+            Self::Units(units) => Length::Units(units),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub(crate) enum Alignment {
     Start,
@@ -42,11 +89,22 @@ impl From<Alignment> for iced_native::Alignment {
     }
 }
 
+impl ToValue for iced_native::Alignment {
+    fn to_value(self) -> toml::Value {
+        serialize(match self {
+            Self::Start => Alignment::Start,
+            Self::Center => Alignment::Center,
+            Self::End => Alignment::End,
+            Self::Fill => Alignment::Fill,
+        })
+    }
+}
+
 pub(crate) mod alignment {
     use super::*;
 
-    #[derive(Debug, Clone, Copy, Deserialize)]
-    #[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+    #[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+    #[cfg_attr(feature = "schema", derive(JsonSchema))]
     #[serde(rename_all = "snake_case")]
     pub(crate) enum Horizontal {
         Left,
@@ -64,8 +122,18 @@ pub(crate) mod alignment {
         }
     }
 
-    #[derive(Debug, Clone, Copy, Deserialize)]
-    #[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+    impl ToValue for iced_native::alignment::Horizontal {
+        fn to_value(self) -> toml::Value {
+            serialize(match self {
+                Self::Left => Horizontal::Left,
+                Self::Center => Horizontal::Center,
+                Self::Right => Horizontal::Right,
+            })
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+    #[cfg_attr(feature = "schema", derive(JsonSchema))]
     #[serde(rename_all = "snake_case")]
     pub(crate) enum Vertical {
         Top,
@@ -82,10 +150,20 @@ pub(crate) mod alignment {
             }
         }
     }
+
+    impl ToValue for iced_native::alignment::Vertical {
+        fn to_value(self) -> toml::Value {
+            serialize(match self {
+                Self::Top => Vertical::Top,
+                Self::Center => Vertical::Center,
+                Self::Bottom => Vertical::Bottom,
+            })
+        }
+    }
 }
 
-#[derive(Debug, Clone, Copy, Deserialize)]
-#[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(untagged)]
 pub(crate) enum Vector {
     Tuple(f32, f32),
@@ -99,3 +177,9 @@ impl From<Vector> for iced_native::Vector {
         }
     }
 }
+
+impl ToValue for iced_native::Vector {
+    fn to_value(self) -> toml::Value {
+        serialize(Vector::Tuple(self.x, self.y))
+    }
+}
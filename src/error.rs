@@ -1,28 +1,55 @@
-use std::io;
+use std::{
+    fmt, io,
+    ops::Range,
+    path::{Path, PathBuf},
+    slice,
+    sync::Arc,
+};
 
 use thiserror::Error;
 
+use crate::color::ColorError;
+
 /// Alias for a `Result` with the error type `iced_style_config::Error`.
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
 /// An error that occurred during parsing the configuration file.
-#[derive(Debug, Error)]
+///
+/// Cloning an error is cheap; the clones share the same underlying error.
+#[derive(Debug, Clone, Error)]
 #[error(transparent)]
-pub struct Error(#[from] ErrorKind);
+pub struct Error(Arc<ErrorKind>);
 
 // Hiding error variants from a library's public error type to prevent
 // dependency updates from becoming breaking changes.
-// We can add `is_*` methods that indicate the kind of error if needed, but
-// don't expose dependencies' types directly in the public API.
+// The kind of error can be queried by `is_*` methods of `Error`, but don't
+// expose dependencies' types directly in the public API.
 #[derive(Debug, Error)]
 pub(crate) enum ErrorKind {
     /// An error that occurred during opening or reading the file.
-    #[error(transparent)]
-    Io(#[from] io::Error),
+    ///
+    /// The path is recorded here when the file is not in an `extends` chain,
+    /// so that the message stays the same as that of `io::Error`.
+    #[error("{error}")]
+    Io { error: io::Error, path: Option<PathBuf> },
 
-    /// An error that occurred during parsing TOML.
+    /// An error that occurred during parsing TOML or the content of the
+    /// configuration file.
     #[error(transparent)]
-    Toml(#[from] toml::de::Error),
+    Parse(Box<ParseError>),
+
+    /// Multiple errors in the content of the configuration file.
+    #[error("{} errors:{}", .0.len(), List(.0))]
+    Multiple(Vec<ParseError>),
+
+    /// `extends` keys in the configuration files form a cycle.
+    #[error("cyclic `extends` chain: {}", Chain(.0))]
+    ExtendsCycle(Vec<PathBuf>),
+
+    /// An error that occurred during loading a file, with the chain of files
+    /// that led to it via `extends`.
+    #[error("failed to load {}: {error}", Chain(.chain))]
+    Load { chain: Vec<PathBuf>, error: Box<ErrorKind> },
 
     #[cfg(not(target_family = "wasm"))]
     #[cfg(feature = "hot-reloading")]
@@ -30,9 +57,526 @@ pub(crate) enum ErrorKind {
     Notify(#[from] notify::Error),
 }
 
+/// Displays a chain of files, e.g., "`a.toml` -> `b.toml`".
+struct Chain<'a>(&'a [PathBuf]);
+
+impl fmt::Display for Chain<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, path) in self.0.iter().enumerate() {
+            if i != 0 {
+                f.write_str(" -> ")?;
+            }
+            write!(f, "`{}`", path.display())?;
+        }
+        Ok(())
+    }
+}
+
+/// Displays errors as a list, one per line.
+struct List<'a>(&'a [ParseError]);
+
+impl fmt::Display for List<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for e in self.0 {
+            write!(f, "\n- {e}")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<ParseError> for ErrorKind {
+    fn from(e: ParseError) -> Self {
+        Self::Parse(Box::new(e))
+    }
+}
+
+impl From<Vec<ParseError>> for ErrorKind {
+    /// Converts non-empty errors into an error.
+    fn from(mut errors: Vec<ParseError>) -> Self {
+        debug_assert!(!errors.is_empty());
+        if errors.len() == 1 {
+            errors.pop().unwrap().into()
+        } else {
+            Self::Multiple(errors)
+        }
+    }
+}
+
+/// An error in the content of a configuration file, with its location.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ParseError {
+    kind: ParseErrorKind,
+    message: String,
+    /// A suggestion for fixing the error, e.g., ", did you mean `hovered`?".
+    suggestion: String,
+    /// The keys to the invalid value, e.g., `["button", "primary", "active"]`.
+    key: Vec<String>,
+    /// The 0-based line and byte column reported by toml.
+    line_col: Option<(usize, usize)>,
+    location: Option<Box<Location>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ParseErrorKind {
+    /// The text is not valid TOML.
+    Syntax,
+    /// A color alias is not defined.
+    UnknownColorAlias,
+    /// A key is not used by any widget.
+    UnknownKey,
+    /// Other invalid values.
+    Invalid,
+}
+
+/// The location of an invalid value.
+#[derive(Clone, PartialEq, Eq)]
+struct Location {
+    /// The file that contains the invalid value.
+    path: Option<PathBuf>,
+    /// The text that contains the invalid value.
+    text: Arc<str>,
+    /// The byte range of the invalid value in `text`.
+    span: Range<usize>,
+}
+
+// Omit the text, which is usually the whole file.
+impl fmt::Debug for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Location")
+            .field("path", &self.path)
+            .field("span", &self.span)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ParseError {
+    pub(crate) fn new(message: impl Into<String>, key: Vec<String>) -> Self {
+        let mut message = message.into();
+        let suggestion = split_suggestion(&mut message);
+        Self {
+            kind: ParseErrorKind::Invalid,
+            message,
+            suggestion,
+            key,
+            line_col: None,
+            location: None,
+        }
+    }
+
+    /// Creates an error for a key that is not used by any widget.
+    pub(crate) fn unknown_key(unknown: &crate::keys::UnknownKey) -> Self {
+        Self::new(unknown.message(), unknown.key.clone()).with_kind(ParseErrorKind::UnknownKey)
+    }
+
+    pub(crate) fn from_color(e: ColorError, key: Vec<String>) -> Self {
+        let kind = if e.unknown_alias {
+            ParseErrorKind::UnknownColorAlias
+        } else {
+            ParseErrorKind::Invalid
+        };
+        Self {
+            kind,
+            message: e.message,
+            suggestion: e.suggestion,
+            key,
+            line_col: None,
+            location: None,
+        }
+    }
+
+    /// Creates an error from an error in parsing TOML text.
+    pub(crate) fn from_toml(e: &toml::de::Error) -> Self {
+        // toml 0.5 does not provide an accessor for the message, so remove the
+        // position that `Display` appends to it.
+        let line_col = e.line_col();
+        let mut message = e.to_string();
+        if let Some((line, col)) = line_col {
+            let suffix = format!(" at line {} column {}", line + 1, col + 1);
+            if message.ends_with(&suffix) {
+                message.truncate(message.len() - suffix.len());
+            }
+        }
+        Self {
+            kind: ParseErrorKind::Syntax,
+            message,
+            suggestion: String::new(),
+            key: vec![],
+            line_col,
+            location: None,
+        }
+    }
+
+    pub(crate) fn with_kind(mut self, kind: ParseErrorKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Prepends `key` to the key path.
+    pub(crate) fn in_key(mut self, key: &str) -> Self {
+        self.key.insert(0, key.to_owned());
+        self
+    }
+
+    /// Records the text that contains the invalid value and locates the value in it.
+    ///
+    /// Returns `self` unchanged if the value cannot be found in the text.
+    pub(crate) fn locate(self, path: Option<&Path>, text: &Arc<str>) -> Self {
+        self.locate_in([(path, text)])
+    }
+
+    /// Locates the invalid value in the text that contains the most keys of
+    /// the key path, preferring earlier ones.
+    pub(crate) fn locate_in<'a>(
+        mut self,
+        sources: impl IntoIterator<Item = (Option<&'a Path>, &'a Arc<str>)>,
+    ) -> Self {
+        let mut found: Option<(Location, usize)> = None;
+        for (path, text) in sources {
+            let (span, depth) = if let Some((line, col)) = self.line_col {
+                let start =
+                    text.split_inclusive('\n').take(line).map(str::len).sum::<usize>() + col;
+                let start = start.min(text.len());
+                let len = text[start..].chars().next().map_or(0, char::len_utf8);
+                (start..start + len, usize::MAX)
+            } else {
+                match crate::span::Spans::parse(text).and_then(|spans| spans.find(&self.key)) {
+                    Some(found) => found,
+                    None => continue,
+                }
+            };
+            if !matches!(&found, Some((_, d)) if depth <= *d) {
+                let path = path.map(Path::to_owned);
+                found = Some((Location { path, text: text.clone(), span }, depth));
+            }
+        }
+        self.location = found.map(|(location, _)| Box::new(location));
+        self
+    }
+
+    /// Returns the file that contains the invalid value, if known.
+    pub(crate) fn path(&self) -> Option<&Path> {
+        self.location.as_ref()?.path.as_deref()
+    }
+
+    /// Returns the byte range of the invalid value in the file, if known.
+    pub(crate) fn span(&self) -> Option<Range<usize>> {
+        Some(self.location.as_ref()?.span.clone())
+    }
+
+    /// Returns the 1-based line and column of the invalid value, if known.
+    pub(crate) fn line_col(&self) -> Option<(usize, usize)> {
+        let Location { text, span, .. } = self.location.as_deref()?;
+        let before = &text[..span.start];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Some((before.matches('\n').count() + 1, before[line_start..].chars().count() + 1))
+    }
+
+    /// Returns the keys to the invalid value.
+    pub(crate) fn keys(&self) -> &[String] {
+        &self.key
+    }
+
+    pub(crate) fn key(&self) -> Option<String> {
+        (!self.key.is_empty()).then(|| self.key.join("."))
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)?;
+        // The message of an unknown key already names the whole key path.
+        if let Some(key) = self.key().filter(|_| self.kind != ParseErrorKind::UnknownKey) {
+            write!(f, " for key `{key}`")?;
+        }
+        match (self.location.as_ref().and_then(|l| l.path.as_ref()), self.line_col()) {
+            (Some(path), Some((line, col))) => write!(f, " at `{}:{line}:{col}`", path.display())?,
+            (None, Some((line, col))) => write!(f, " at line {line} column {col}")?,
+            _ => {}
+        }
+        f.write_str(&self.suggestion)
+    }
+}
+
+/// Removes the suggestion from the end of a message created by
+/// [`crate::suggest::did_you_mean`], and returns it.
+fn split_suggestion(message: &mut String) -> String {
+    match message.rfind(crate::suggest::DID_YOU_MEAN) {
+        Some(i) if message.ends_with("`?") => message.split_off(i),
+        _ => String::new(),
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl serde::de::Error for ParseError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Payload::decode(&format!("{msg:#}")).unwrap_or_else(|| Self::new(msg.to_string(), vec![]))
+    }
+
+    fn unknown_variant(variant: &str, expected: &'static [&'static str]) -> Self {
+        let message = serde::de::value::Error::unknown_variant(variant, expected).to_string();
+        let suggestion = crate::suggest::did_you_mean(variant, expected.iter().copied());
+        Self { suggestion, ..Self::new(message, vec![]) }
+    }
+
+    fn unknown_field(field: &str, expected: &'static [&'static str]) -> Self {
+        let message = serde::de::value::Error::unknown_field(field, expected).to_string();
+        let suggestion = crate::suggest::did_you_mean(field, expected.iter().copied());
+        Self { suggestion, ..Self::new(message, vec![]) }
+    }
+}
+
+/// Passes a [`ParseError`] through [`serde::de::Error::custom`] of another
+/// error type, e.g., the error of the deserializer of a flattened field.
+///
+/// Formatting this with `{:#}` gives an encoded form that the `custom` of
+/// [`ParseError`] decodes back to the error, including its kind, suggestion,
+/// and key path. Formatting this with `{}` gives the message, which other
+/// error types keep.
+pub(crate) struct Payload<'a>(pub(crate) &'a ParseError);
+
+impl Payload<'_> {
+    /// Separates the fields of the encoded form, and precedes them.
+    const SEPARATOR: char = '\0';
+
+    fn decode(s: &str) -> Option<ParseError> {
+        let mut fields = s.strip_prefix(Self::SEPARATOR)?.split(Self::SEPARATOR);
+        let kind = match fields.next()? {
+            "Syntax" => ParseErrorKind::Syntax,
+            "UnknownColorAlias" => ParseErrorKind::UnknownColorAlias,
+            "UnknownKey" => ParseErrorKind::UnknownKey,
+            "Invalid" => ParseErrorKind::Invalid,
+            _ => return None,
+        };
+        let message = fields.next()?.to_owned();
+        let suggestion = fields.next()?.to_owned();
+        let key = fields.map(str::to_owned).collect();
+        Some(ParseError { kind, message, suggestion, key, line_col: None, location: None })
+    }
+}
+
+impl fmt::Display for Payload<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let e = self.0;
+        if !f.alternate() {
+            return write!(f, "{}{}", e.message, e.suggestion);
+        }
+        let sep = Self::SEPARATOR;
+        write!(f, "{sep}{:?}{sep}{}{sep}{}", e.kind, e.message, e.suggestion)?;
+        for key in &e.key {
+            write!(f, "{sep}{key}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Renders an error with an annotated source snippet.
+struct Snippet<'a>(&'a ErrorKind);
+
+impl fmt::Display for Snippet<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("error: ")?;
+        let mut kind = self.0;
+        while let ErrorKind::Load { chain, error } = kind {
+            write!(f, "failed to load {}: ", Chain(chain))?;
+            kind = error;
+        }
+        match kind {
+            ErrorKind::Parse(e) => e.write_snippet(f),
+            ErrorKind::Multiple(errors) => {
+                for (i, e) in errors.iter().enumerate() {
+                    if i != 0 {
+                        f.write_str("\nerror: ")?;
+                    }
+                    e.write_snippet(f)?;
+                }
+                Ok(())
+            }
+            _ => writeln!(f, "{kind}"),
+        }
+    }
+}
+
+impl ParseError {
+    /// Writes the message followed by an annotated snippet of the text that
+    /// contains the invalid value.
+    pub(crate) fn write_snippet(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}{}", self.message, self.suggestion)?;
+        let (Some(Location { path, text, span }), Some((line, col))) =
+            (self.location.as_deref(), self.line_col())
+        else {
+            return match self.key() {
+                Some(key) => writeln!(f, "  = key: `{key}`"),
+                None => Ok(()),
+            };
+        };
+
+        let line_text = text.lines().nth(line - 1).unwrap_or_default();
+        let width = line.to_string().len();
+        let location = match path {
+            Some(path) => format!("{}:{line}:{col}", path.display()),
+            None => format!("{line}:{col}"),
+        };
+        // The span may continue to the following lines; underline only the
+        // part on the first line.
+        let start = span.start - text[..span.start].rfind('\n').map_or(0, |i| i + 1);
+        let end = (start + span.len()).min(line_text.len());
+        let indent = line_text[..start].chars().count();
+        let carets = line_text[start..end].chars().count().max(1);
+        writeln!(f, "{:width$}--> {location}", "")?;
+        writeln!(f, "{:width$} |", "")?;
+        writeln!(f, "{line:width$} | {line_text}")?;
+        write!(f, "{:width$} | {:indent$}{}", "", "", "^".repeat(carets))?;
+        match self.key() {
+            Some(key) => writeln!(f, " {key}"),
+            None => writeln!(f),
+        }
+    }
+}
+
 impl Error {
     pub(crate) fn new(e: impl Into<ErrorKind>) -> Self {
-        Self(e.into())
+        Self(Arc::new(e.into()))
+    }
+
+    /// Returns a value that displays this error with an annotated snippet of
+    /// the configuration file, if the location of the error is known.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iced_style_config::Theme;
+    ///
+    /// let e = "[text.default]\ncolor = \"UNKNOWN\"".parse::<Theme>().unwrap_err();
+    /// assert_eq!(
+    ///     e.snippet().to_string(),
+    ///     "\
+    /// error: cannot found color alias `unknown`
+    ///  --> 2:1
+    ///   |
+    /// 2 | color = \"UNKNOWN\"
+    ///   | ^^^^^^^^^^^^^^^^^ text.default.color
+    /// ",
+    /// );
+    /// ```
+    pub fn snippet(&self) -> impl fmt::Display + '_ {
+        Snippet(&self.0)
+    }
+}
+
+/// Methods that classify the error and locate its cause.
+///
+/// If there are multiple errors in the content of the configuration file,
+/// `is_*` methods return `true` if any of them is of that kind, and the
+/// location methods return the location of the first one.
+impl Error {
+    /// Returns `true` if the error occurred during opening or reading a file.
+    pub fn is_io(&self) -> bool {
+        matches!(self.0.root(), ErrorKind::Io { .. })
+    }
+
+    /// Returns `true` if a file was not found.
+    pub fn is_not_found(&self) -> bool {
+        matches!(self.0.root(), ErrorKind::Io { error, .. } if error.kind() == io::ErrorKind::NotFound)
+    }
+
+    /// Returns `true` if a configuration file is not valid TOML.
+    pub fn is_syntax(&self) -> bool {
+        self.0.has_parse_error(ParseErrorKind::Syntax)
+    }
+
+    /// Returns `true` if a color alias used in the configuration file is not
+    /// defined.
+    pub fn is_unknown_color_alias(&self) -> bool {
+        self.0.has_parse_error(ParseErrorKind::UnknownColorAlias)
+    }
+
+    /// Returns `true` if a key is not used by any widget.
+    ///
+    /// This kind of error is only reported in [strict](crate::ParseOptions::strict) mode.
+    pub fn is_unknown_key(&self) -> bool {
+        self.0.has_parse_error(ParseErrorKind::UnknownKey)
+    }
+
+    /// Returns `true` if a value in the configuration file is invalid, e.g.,
+    /// the value has the wrong type or a color alias refers to itself.
+    pub fn is_invalid_value(&self) -> bool {
+        self.0.has_parse_error(ParseErrorKind::Invalid)
+    }
+
+    /// Returns `true` if `extends` keys in the configuration files form a cycle.
+    pub fn is_extends_cycle(&self) -> bool {
+        matches!(self.0.root(), ErrorKind::ExtendsCycle(_))
+    }
+
+    /// Returns `true` if the error occurred during watching a file for hot
+    /// reloading.
+    pub fn is_watch(&self) -> bool {
+        match self.0.root() {
+            #[cfg(not(target_family = "wasm"))]
+            #[cfg(feature = "hot-reloading")]
+            ErrorKind::Notify(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns the path of the file that caused the error, if known.
+    pub fn path(&self) -> Option<&Path> {
+        self.0.path()
+    }
+
+    /// Returns the dotted key path of the invalid value (e.g.,
+    /// `button.primary.hovered.background`), if known.
+    pub fn key(&self) -> Option<String> {
+        self.0.parse_errors().first()?.key()
+    }
+
+    /// Returns the 1-based line and column of the invalid value in the file,
+    /// if known.
+    pub fn line_col(&self) -> Option<(usize, usize)> {
+        self.0.parse_errors().first()?.line_col()
+    }
+
+    /// Returns the byte range of the invalid value in the file, if known.
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.0.parse_errors().first()?.span()
+    }
+}
+
+impl ErrorKind {
+    /// Returns the error without the chain of files that led to it.
+    fn root(&self) -> &Self {
+        match self {
+            Self::Load { error, .. } => error.root(),
+            _ => self,
+        }
+    }
+
+    fn parse_errors(&self) -> &[ParseError] {
+        match self.root() {
+            Self::Parse(e) => slice::from_ref(e),
+            Self::Multiple(errors) => errors,
+            _ => &[],
+        }
+    }
+
+    fn has_parse_error(&self, kind: ParseErrorKind) -> bool {
+        self.parse_errors().iter().any(|e| e.kind == kind)
+    }
+
+    fn path(&self) -> Option<&Path> {
+        match self {
+            Self::Load { chain, error } => error.path().or_else(|| chain.last().map(|p| &**p)),
+            Self::ExtendsCycle(chain) => chain.last().map(|p| &**p),
+            Self::Io { path, .. } => path.as_deref(),
+            _ => self.parse_errors().first()?.path(),
+        }
+    }
+}
+
+impl From<io::Error> for ErrorKind {
+    fn from(error: io::Error) -> Self {
+        Self::Io { error, path: None }
     }
 }
 
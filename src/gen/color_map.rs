@@ -5,7 +5,7 @@
 #![allow(clippy::wildcard_imports)]
 
 use crate::*;
-pub(crate) static DEFAULT_COLOR_ALIASES: once_cell::sync::Lazy<color::ColorMap> = once_cell::sync::Lazy::new(||
+pub(crate) static DEFAULT_COLOR_ALIASES: std::sync::LazyLock<color::ColorMap> = std::sync::LazyLock::new(||
 {
     let mut map = color::ColorMap::with_capacity(157usize);
     map.insert("transparent", iced_native::Color::TRANSPARENT);
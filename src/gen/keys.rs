@@ -0,0 +1,404 @@
+// This file is @generated by iced_style_config-internal-codegen.
+// It is not intended for manual editing.
+
+#![cfg_attr(rustfmt, rustfmt::skip)]
+#![allow(clippy::wildcard_imports)]
+
+use crate::keys::Key;
+/// The keys of the top-level table.
+pub(crate) const KEYS: &[Key] = &[
+    Key::map(
+        "button",
+        &[
+            Key::table(
+                "active",
+                &[
+                    Key::value("background"),
+                    Key::value("border_color"),
+                    Key::value("border_radius"),
+                    Key::value("border_width"),
+                    Key::value("shadow_offset"),
+                    Key::value("text_color"),
+                ],
+            ),
+            Key::table(
+                "disabled",
+                &[
+                    Key::value("background"),
+                    Key::value("border_color"),
+                    Key::value("border_radius"),
+                    Key::value("border_width"),
+                    Key::value("shadow_offset"),
+                    Key::value("text_color"),
+                ],
+            ),
+            Key::value("height"),
+            Key::table(
+                "hovered",
+                &[
+                    Key::value("background"),
+                    Key::value("border_color"),
+                    Key::value("border_radius"),
+                    Key::value("border_width"),
+                    Key::value("shadow_offset"),
+                    Key::value("text_color"),
+                ],
+            ),
+            Key::value("padding"),
+            Key::table(
+                "pressed",
+                &[
+                    Key::value("background"),
+                    Key::value("border_color"),
+                    Key::value("border_radius"),
+                    Key::value("border_width"),
+                    Key::value("shadow_offset"),
+                    Key::value("text_color"),
+                ],
+            ),
+            Key::value("width"),
+        ],
+    ),
+    Key::map(
+        "checkbox",
+        &[
+            Key::table(
+                "active",
+                &[
+                    Key::value("background"),
+                    Key::value("border_color"),
+                    Key::value("border_radius"),
+                    Key::value("border_width"),
+                    Key::table(
+                        "checked",
+                        &[
+                            Key::value("background"),
+                            Key::value("border_color"),
+                            Key::value("border_radius"),
+                            Key::value("border_width"),
+                            Key::value("checkmark_color"),
+                            Key::value("text_color"),
+                        ],
+                    ),
+                    Key::value("checkmark_color"),
+                    Key::value("text_color"),
+                ],
+            ),
+            Key::table(
+                "hovered",
+                &[
+                    Key::value("background"),
+                    Key::value("border_color"),
+                    Key::value("border_radius"),
+                    Key::value("border_width"),
+                    Key::table(
+                        "checked",
+                        &[
+                            Key::value("background"),
+                            Key::value("border_color"),
+                            Key::value("border_radius"),
+                            Key::value("border_width"),
+                            Key::value("checkmark_color"),
+                            Key::value("text_color"),
+                        ],
+                    ),
+                    Key::value("checkmark_color"),
+                    Key::value("text_color"),
+                ],
+            ),
+            Key::value("size"),
+            Key::value("spacing"),
+            Key::value("text_size"),
+            Key::value("width"),
+        ],
+    ),
+    Key::value("color"),
+    Key::map(
+        "container",
+        &[
+            Key::value("align_x"),
+            Key::value("align_y"),
+            Key::value("background"),
+            Key::value("border_color"),
+            Key::value("border_radius"),
+            Key::value("border_width"),
+            Key::value("height"),
+            Key::value("max_height"),
+            Key::value("max_width"),
+            Key::value("padding"),
+            Key::value("text_color"),
+            Key::value("width"),
+        ],
+    ),
+    Key::value("extends"),
+    Key::map("image", &[Key::value("height"), Key::value("width")]),
+    Key::map(
+        "image_viewer",
+        &[
+            Key::value("height"),
+            Key::value("max_scale"),
+            Key::value("min_scale"),
+            Key::value("padding"),
+            Key::value("scale_step"),
+            Key::value("width"),
+        ],
+    ),
+    Key::map(
+        "pick_list",
+        &[
+            Key::table(
+                "active",
+                &[
+                    Key::value("background"),
+                    Key::value("border_color"),
+                    Key::value("border_radius"),
+                    Key::value("border_width"),
+                    Key::value("icon_size"),
+                    Key::value("placeholder_color"),
+                    Key::value("text_color"),
+                ],
+            ),
+            Key::table(
+                "hovered",
+                &[
+                    Key::value("background"),
+                    Key::value("border_color"),
+                    Key::value("border_radius"),
+                    Key::value("border_width"),
+                    Key::value("icon_size"),
+                    Key::value("placeholder_color"),
+                    Key::value("text_color"),
+                ],
+            ),
+            Key::table(
+                "menu",
+                &[
+                    Key::value("background"),
+                    Key::value("border_color"),
+                    Key::value("border_width"),
+                    Key::value("selected_background"),
+                    Key::value("selected_text_color"),
+                    Key::value("text_color"),
+                ],
+            ),
+            Key::value("padding"),
+            Key::value("text_size"),
+            Key::value("width"),
+        ],
+    ),
+    Key::map(
+        "progress_bar",
+        &[
+            Key::value("background"),
+            Key::value("bar"),
+            Key::value("border_radius"),
+            Key::value("height"),
+            Key::value("width"),
+        ],
+    ),
+    #[cfg(feature = "qr_code")]
+    Key::map(
+        "qr_code",
+        &[Key::value("cell_size"), Key::value("dark"), Key::value("light")],
+    ),
+    Key::map(
+        "radio",
+        &[
+            Key::table(
+                "active",
+                &[
+                    Key::value("background"),
+                    Key::value("border_color"),
+                    Key::value("border_width"),
+                    Key::value("dot_color"),
+                    Key::value("text_color"),
+                ],
+            ),
+            Key::table(
+                "hovered",
+                &[
+                    Key::value("background"),
+                    Key::value("border_color"),
+                    Key::value("border_width"),
+                    Key::value("dot_color"),
+                    Key::value("text_color"),
+                ],
+            ),
+            Key::value("size"),
+            Key::value("spacing"),
+            Key::value("text_size"),
+            Key::value("width"),
+        ],
+    ),
+    Key::map(
+        "scrollable",
+        &[
+            Key::table(
+                "active",
+                &[
+                    Key::value("background"),
+                    Key::value("border_color"),
+                    Key::value("border_radius"),
+                    Key::value("border_width"),
+                    Key::table(
+                        "scroller",
+                        &[
+                            Key::value("border_color"),
+                            Key::value("border_radius"),
+                            Key::value("border_width"),
+                            Key::value("color"),
+                        ],
+                    ),
+                ],
+            ),
+            Key::value("align_items"),
+            Key::table(
+                "dragging",
+                &[
+                    Key::value("background"),
+                    Key::value("border_color"),
+                    Key::value("border_radius"),
+                    Key::value("border_width"),
+                    Key::table(
+                        "scroller",
+                        &[
+                            Key::value("border_color"),
+                            Key::value("border_radius"),
+                            Key::value("border_width"),
+                            Key::value("color"),
+                        ],
+                    ),
+                ],
+            ),
+            Key::value("height"),
+            Key::table(
+                "hovered",
+                &[
+                    Key::value("background"),
+                    Key::value("border_color"),
+                    Key::value("border_radius"),
+                    Key::value("border_width"),
+                    Key::table(
+                        "scroller",
+                        &[
+                            Key::value("border_color"),
+                            Key::value("border_radius"),
+                            Key::value("border_width"),
+                            Key::value("color"),
+                        ],
+                    ),
+                ],
+            ),
+            Key::value("max_height"),
+            Key::value("max_width"),
+            Key::value("padding"),
+            Key::value("scrollbar_margin"),
+            Key::value("scrollbar_width"),
+            Key::value("scroller_width"),
+            Key::value("spacing"),
+            Key::value("width"),
+        ],
+    ),
+    Key::map(
+        "slider",
+        &[
+            Key::table(
+                "active",
+                &[
+                    Key::table(
+                        "handle",
+                        &[
+                            Key::value("border_color"),
+                            Key::value("border_width"),
+                            Key::value("color"),
+                            Key::value("shape"),
+                        ],
+                    ),
+                    Key::value("rail_colors"),
+                ],
+            ),
+            Key::table(
+                "dragging",
+                &[
+                    Key::table(
+                        "handle",
+                        &[
+                            Key::value("border_color"),
+                            Key::value("border_width"),
+                            Key::value("color"),
+                            Key::value("shape"),
+                        ],
+                    ),
+                    Key::value("rail_colors"),
+                ],
+            ),
+            Key::value("height"),
+            Key::table(
+                "hovered",
+                &[
+                    Key::table(
+                        "handle",
+                        &[
+                            Key::value("border_color"),
+                            Key::value("border_width"),
+                            Key::value("color"),
+                            Key::value("shape"),
+                        ],
+                    ),
+                    Key::value("rail_colors"),
+                ],
+            ),
+            Key::value("width"),
+        ],
+    ),
+    Key::map("svg", &[Key::value("height"), Key::value("width")]),
+    Key::map(
+        "text",
+        &[
+            Key::value("color"),
+            Key::value("height"),
+            Key::value("horizontal_alignment"),
+            Key::value("size"),
+            Key::value("vertical_alignment"),
+            Key::value("width"),
+        ],
+    ),
+    Key::map(
+        "text_input",
+        &[
+            Key::table(
+                "active",
+                &[
+                    Key::value("background"),
+                    Key::value("border_color"),
+                    Key::value("border_radius"),
+                    Key::value("border_width"),
+                ],
+            ),
+            Key::table(
+                "focused",
+                &[
+                    Key::value("background"),
+                    Key::value("border_color"),
+                    Key::value("border_radius"),
+                    Key::value("border_width"),
+                ],
+            ),
+            Key::table(
+                "hovered",
+                &[
+                    Key::value("background"),
+                    Key::value("border_color"),
+                    Key::value("border_radius"),
+                    Key::value("border_width"),
+                ],
+            ),
+            Key::value("padding"),
+            Key::value("placeholder_color"),
+            Key::value("selection_color"),
+            Key::value("size"),
+            Key::value("value_color"),
+            Key::value("width"),
+        ],
+    ),
+];
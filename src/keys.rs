@@ -0,0 +1,98 @@
+//! The keys of configuration files.
+
+/// A key of a table in the configuration file.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Key {
+    pub(crate) name: &'static str,
+    pub(crate) kind: Kind,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Kind {
+    /// A value whose content is not checked, e.g., a color or a length.
+    Value,
+    /// A table with the given keys.
+    Table(&'static [Key]),
+    /// A table with arbitrary keys (e.g., names of style sheets) whose values
+    /// are tables with the given keys.
+    Map(&'static [Key]),
+}
+
+impl Key {
+    pub(crate) const fn value(name: &'static str) -> Self {
+        Self { name, kind: Kind::Value }
+    }
+
+    pub(crate) const fn table(name: &'static str, keys: &'static [Key]) -> Self {
+        Self { name, kind: Kind::Table(keys) }
+    }
+
+    pub(crate) const fn map(name: &'static str, keys: &'static [Key]) -> Self {
+        Self { name, kind: Kind::Map(keys) }
+    }
+}
+
+// The keys are generated from the schema of the deserializers so that they
+// are always consistent with the fields that are actually read.
+#[path = "gen/keys.rs"]
+mod gen;
+pub(crate) use self::gen::KEYS;
+
+/// A key that is not used by any widget.
+#[derive(Debug)]
+pub(crate) struct UnknownKey {
+    /// The path of keys to the unknown key, including itself.
+    pub(crate) key: Vec<String>,
+    /// The known key in the same table that is closest to the unknown key.
+    pub(crate) suggestion: Option<&'static str>,
+}
+
+impl UnknownKey {
+    /// Returns the message that describes this key, e.g., "unknown key
+    /// `button.default.hoverd`, did you mean `hovered`?".
+    pub(crate) fn message(&self) -> String {
+        let name = self.key.join(".");
+        match self.suggestion {
+            Some(s) => format!("unknown key `{name}`{}`{s}`?", crate::suggest::DID_YOU_MEAN),
+            None => format!("unknown key `{name}`"),
+        }
+    }
+}
+
+/// Returns the keys in `value` that are not used by any widget.
+pub(crate) fn unknown_keys(value: &toml::Value) -> Vec<UnknownKey> {
+    let mut unknown = vec![];
+    check(value, KEYS, &mut vec![], &mut unknown);
+    unknown
+}
+
+fn check(value: &toml::Value, keys: &[Key], path: &mut Vec<String>, unknown: &mut Vec<UnknownKey>) {
+    let Some(table) = value.as_table() else { return };
+    for (name, value) in table {
+        path.push(name.clone());
+        match keys.iter().find(|k| k.name == name) {
+            None => unknown.push(UnknownKey {
+                key: path.clone(),
+                suggestion: crate::suggest::closest(name, keys.iter().map(|k| k.name)),
+            }),
+            Some(Key { kind: Kind::Value, .. }) => {}
+            Some(Key { kind: Kind::Table(keys), .. }) => check(value, keys, path, unknown),
+            Some(Key { kind: Kind::Map(keys), .. }) => {
+                for (name, value) in value.as_table().into_iter().flatten() {
+                    path.push(name.clone());
+                    check(value, keys, path, unknown);
+                    path.pop();
+                }
+            }
+        }
+        path.pop();
+    }
+}
+
+/// Returns the widgets and the names of the style sheets defined in `value`.
+pub(crate) fn style_sheets(value: &toml::Value) -> impl Iterator<Item = (&'static str, &str)> {
+    KEYS.iter()
+        .filter(|k| matches!(k.kind, Kind::Map(_)))
+        .filter_map(move |k| Some((k.name, value.get(k.name)?.as_table()?)))
+        .flat_map(|(widget, table)| table.keys().map(move |name| (widget, &**name)))
+}
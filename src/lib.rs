@@ -29,6 +29,21 @@ Note: Hot reloading on WebAssembly is not yet supported.
 cargo run --example hot_reloading
 ```
 
+## Theme inheritance
+
+A configuration file can inherit from another configuration file by using the `extends` key. The path is resolved relative to the file that contains the key.
+
+```toml
+extends = "base_theme.toml"
+
+[color]
+ACCENT = [0.45, 0.54, 0.85]
+```
+
+Style sheets and colors are deep-merged with those defined in the inherited file, and the values in the inheriting file take precedence.
+
+`ReloadableTheme` watches the inherited files as well, so editing a shared file (e.g., a palette) reloads every theme that inherits from it.
+
 ## Schemas for configuration files
 
 The `schema.json` is the JSON schemas for the configuration files, and when combined with an extension of the editor that supports completion using the JSON schema, completion can be enabled.
@@ -79,11 +94,19 @@ For example:
 pub mod widget;
 pub use widget::*;
 
+mod builder;
 pub mod color;
 mod colors;
 mod core;
 mod error;
+mod keys;
+mod lint;
+mod load;
+mod span;
+mod suggest;
 mod theme;
+mod value;
+mod warning;
 
 #[cfg(feature = "hot-reloading")]
 #[cfg_attr(docsrs, doc(cfg(feature = "hot-reloading")))]
@@ -92,8 +115,10 @@ pub mod reloadable;
 pub use reloadable::ReloadableTheme;
 
 pub use crate::{
+    builder::{ColorValue, ThemeBuilder},
     error::{Error, Result},
     theme::Theme,
+    warning::{ParseOptions, Warning},
 };
 
 mod import {
@@ -102,7 +127,7 @@ mod import {
         borrow::{Borrow, Cow},
         cell::RefCell,
         collections::{BTreeMap, HashMap},
-        fs,
+        fmt, fs,
         ops::{self, Index, RangeInclusive},
         path::Path,
         str::FromStr,
@@ -111,14 +136,15 @@ mod import {
     #[cfg(feature = "schema")]
     pub(crate) use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
     pub(crate) use scoped_tls::scoped_thread_local;
-    #[cfg(feature = "schema")]
-    pub(crate) use serde::{Serialize, Serializer};
 
     pub(crate) use crate::{
+        builder::{serialize, ColorValue, ToValue},
         color::{Background, Color},
         core::*,
         de::*,
-        Error,
+        ser::*,
+        widget::Resolved,
+        Error, ParseOptions, Warning,
     };
 }
 
@@ -127,14 +153,84 @@ mod de {
 
     #![allow(single_use_lifetimes)] // https://github.com/rust-lang/rust/issues/55058
 
+    use std::{fmt, marker::PhantomData};
+
+    use serde::de::{self, value::MapAccessDeserializer, IntoDeserializer};
     pub(crate) use serde::{de::Error as _, Deserialize, Deserializer};
 
+    use crate::error::Payload;
+
+    /// An enum that is deserialized by [`deserialize_enum`].
+    pub(crate) trait DeserializeEnum<'de>: Sized {
+        /// Deserializes the enum using the implementation derived by serde.
+        fn deserialize_derived<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>;
+    }
+
+    /// Deserializes an externally tagged enum from a string or a table.
+    ///
+    /// toml's `Value` deserializer can only deserialize unit variants, and
+    /// toml's deserializer cannot deserialize enums from dotted keys, so
+    /// this uses `deserialize_any` instead of `deserialize_enum`.
+    pub(crate) fn deserialize_enum<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: DeserializeEnum<'de>,
+    {
+        struct Visitor<T>(PhantomData<T>);
+
+        impl<'de, T: DeserializeEnum<'de>> de::Visitor<'de> for Visitor<T> {
+            type Value = T;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("string or table")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+                T::deserialize_derived(v.into_deserializer())
+            }
+
+            fn visit_map<A: de::MapAccess<'de>>(self, map: A) -> Result<T, A::Error> {
+                T::deserialize_derived(MapAccessDeserializer::new(map))
+            }
+        }
+
+        deserializer.deserialize_any(Visitor(PhantomData))
+    }
+
+    /// Deserializes a field with `#[serde(flatten)]`.
+    ///
+    /// serde deserializes a flattened field from a buffer that does not track
+    /// the keys, so this deserializes the field from a TOML value instead, and
+    /// passes the error with the key path in the field through `D::Error`.
+    pub(crate) fn flatten<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: de::DeserializeOwned,
+    {
+        let value = toml::Value::deserialize(deserializer)?;
+        crate::value::from_value(&value).map_err(|e| D::Error::custom(Payload(&e)))
+    }
+
+    /// Deserializes an optional field with `#[serde(flatten)]`.
+    ///
+    /// serde deserializes a flattened `Option` as `None` when the inner value
+    /// fails to deserialize, which hides errors such as an unknown color alias.
+    pub(crate) fn flatten_option<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: de::DeserializeOwned,
+    {
+        flatten(deserializer).map(Some)
+    }
+
     pub(crate) fn from_hex<'de, D>(deserializer: D) -> Result<u8, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let s: &str = Deserialize::deserialize(deserializer)?;
-        parse_hex::<D>(s)
+        let s: String = Deserialize::deserialize(deserializer)?;
+        parse_hex::<D>(&s)
     }
 
     // https://stackoverflow.com/questions/46753955/how-to-transform-fields-during-deserialization-using-serde
@@ -153,7 +249,33 @@ mod de {
     where
         D: Deserializer<'de>,
     {
-        let s: &str = Deserialize::deserialize(deserializer)?;
+        let s: String = Deserialize::deserialize(deserializer)?;
         s.parse().map_err(D::Error::custom)
     }
 }
+
+mod ser {
+    //! Helpers for serializing.
+
+    pub(crate) use serde::{ser::SerializeMap, Serialize, Serializer};
+
+    #[allow(clippy::trivially_copy_pass_by_ref)] // signature required by serde
+    pub(crate) fn to_hex<S>(v: &u8, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&format_args!("0x{v:02X}"))
+    }
+
+    #[allow(clippy::trivially_copy_pass_by_ref)] // signature required by serde
+    pub(crate) fn to_f32_str<S>(v: &f32, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(v)
+    }
+
+    pub(crate) fn is_default<T: Default + PartialEq>(v: &T) -> bool {
+        *v == T::default()
+    }
+}
@@ -0,0 +1,61 @@
+//! Checks for non-fatal problems in configuration files.
+
+use crate::import::*;
+
+/// Returns the problems found in `value`, except for the ones that can only be
+/// found after resolving the colors.
+pub(crate) fn check(value: &toml::Value, options: &ParseOptions) -> Vec<Warning> {
+    let mut warnings = vec![];
+    if options.warn_unknown_keys && !options.strict {
+        for unknown in crate::keys::unknown_keys(value) {
+            warnings.push(Warning::unknown_key(&unknown));
+        }
+    }
+    if options.warn_unused_colors {
+        unused_colors(value, &mut warnings);
+    }
+    // Style sheets are looked up by name from Rust code, so the only style
+    // sheets known to be never used are the ones named like `default`.
+    for (widget, name) in crate::keys::style_sheets(value) {
+        if name != "default" && name.eq_ignore_ascii_case("default") {
+            warnings.push(Warning::new(
+                format!(
+                    "style sheet `{name}` is never used as the default style sheet because \
+                     style sheet names are case-sensitive, did you mean `default`?"
+                ),
+                vec![widget.to_owned(), name.to_owned()],
+            ));
+        }
+    }
+    warnings
+}
+
+/// Reports color aliases that are not referred to by any other value.
+fn unused_colors(value: &toml::Value, warnings: &mut Vec<Warning>) {
+    /// Returns `true` if a string in `value`, except for `skip`, refers to `name`.
+    fn refers(value: &toml::Value, name: &str, skip: &toml::Value) -> bool {
+        if std::ptr::eq(value, skip) {
+            return false;
+        }
+        match value {
+            // Aliases can appear as arguments of color functions, e.g.,
+            // "lighten(ACCENT, 10%)".
+            toml::Value::String(s) => s
+                .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .any(|word| word.eq_ignore_ascii_case(name)),
+            toml::Value::Array(a) => a.iter().any(|v| refers(v, name, skip)),
+            toml::Value::Table(t) => t.values().any(|v| refers(v, name, skip)),
+            _ => false,
+        }
+    }
+
+    let Some(colors) = value.get("color").and_then(toml::Value::as_table) else { return };
+    for (name, color) in colors {
+        if !refers(value, name, color) {
+            warnings.push(Warning::new(
+                format!("color alias `{name}` is never used"),
+                vec!["color".to_owned(), name.clone()],
+            ));
+        }
+    }
+}
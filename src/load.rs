@@ -0,0 +1,260 @@
+//! Loading of configuration files.
+
+use std::{io, path::PathBuf, slice, sync::Arc};
+
+use crate::{
+    error::{ErrorKind, ParseError},
+    import::*,
+    keys::{self, Key, Kind},
+    Theme,
+};
+
+/// The key that specifies the configuration file to inherit from.
+const EXTENDS: &str = "extends";
+
+/// Creates a new theme from a string of TOML text.
+///
+/// `extends` in the text is resolved relative to the current directory.
+pub(crate) fn from_str(s: &str, options: &ParseOptions) -> Result<(Theme, Vec<Warning>), Error> {
+    let source = Source { path: None, text: s.into() };
+    let mut value = source.parse().map_err(Error::new)?;
+    match take_extends(&mut value).map_err(|e| Error::new(source.locate(e)))? {
+        Some(parent) => Loader::new(source).extend(Path::new(""), &parent, value, options),
+        None => locate(Theme::from_value_with_options(value, options), slice::from_ref(&source)),
+    }
+}
+
+/// Creates a new theme from a TOML file.
+///
+/// `extends` in the file is resolved relative to the directory containing the file.
+pub(crate) fn from_file(
+    path: &Path,
+    options: &ParseOptions,
+) -> Result<(Theme, Vec<Warning>), Error> {
+    from_file_with_deps(path, options, &mut vec![])
+}
+
+/// A file read during loading.
+#[derive(Debug)]
+pub(crate) struct File {
+    pub(crate) path: PathBuf,
+    /// The text of the file as read, or `None` if it could not be read.
+    #[cfg_attr(not(feature = "hot-reloading"), allow(dead_code))] // used to detect changes
+    pub(crate) text: Option<Arc<str>>,
+}
+
+/// Creates a new theme from a TOML file, and records the files read to
+/// `files`, from the outermost one.
+///
+/// The files include the files inherited via `extends`. They are recorded even
+/// if loading fails, including the file that failed to load.
+pub(crate) fn from_file_with_deps(
+    path: &Path,
+    options: &ParseOptions,
+    files: &mut Vec<File>,
+) -> Result<(Theme, Vec<Warning>), Error> {
+    let source = Source::read(path);
+    let text = source.as_ref().ok().map(|s| s.text.clone());
+    files.push(File { path: path.to_owned(), text });
+    let source =
+        source.map_err(|error| Error::new(ErrorKind::Io { error, path: Some(path.to_owned()) }))?;
+    let mut value = source.parse().map_err(Error::new)?;
+    match take_extends(&mut value).map_err(|e| Error::new(source.locate(e)))? {
+        Some(parent) => {
+            let mut loader = Loader::new(source);
+            loader.push(path)?;
+            let result = loader.extend(parent_dir(path), &parent, value, options);
+            files.extend(loader.files);
+            result
+        }
+        None => locate(Theme::from_value_with_options(value, options), slice::from_ref(&source)),
+    }
+}
+
+/// The text of a configuration file.
+struct Source {
+    path: Option<PathBuf>,
+    text: Arc<str>,
+}
+
+impl Source {
+    fn read(path: &Path) -> io::Result<Self> {
+        Ok(Self { path: Some(path.to_owned()), text: fs::read_to_string(path)?.into() })
+    }
+
+    fn parse(&self) -> Result<toml::Value, ParseError> {
+        toml::from_str(&self.text).map_err(|e| self.locate(ParseError::from_toml(&e)))
+    }
+
+    /// Locates the invalid value of `e` in this file.
+    fn locate(&self, e: ParseError) -> ParseError {
+        e.locate(self.path.as_deref(), &self.text)
+    }
+}
+
+struct Loader {
+    /// The files currently being loaded, from the outermost one.
+    ///
+    /// Each entry is the path as written and its canonicalized form.
+    stack: Vec<(PathBuf, PathBuf)>,
+    /// All the files loaded, from the outermost one.
+    sources: Vec<Source>,
+    /// The files inherited via `extends`, including the ones that failed to
+    /// load.
+    files: Vec<File>,
+}
+
+impl Loader {
+    fn new(source: Source) -> Self {
+        Self { stack: vec![], sources: vec![source], files: vec![] }
+    }
+
+    fn push(&mut self, path: &Path) -> Result<(), Error> {
+        let chain = || self.stack.iter().map(|(p, _)| p.clone()).chain(Some(path.to_owned()));
+        let canonical = fs::canonicalize(path).map_err(|e| {
+            Error::new(ErrorKind::Load { chain: chain().collect(), error: Box::new(e.into()) })
+        })?;
+        if self.stack.iter().any(|(_, c)| *c == canonical) {
+            return Err(Error::new(ErrorKind::ExtendsCycle(chain().collect())));
+        }
+        self.stack.push((path.to_owned(), canonical));
+        Ok(())
+    }
+
+    /// Loads `parent` and overlays `value` on it.
+    fn extend(
+        &mut self,
+        dir: &Path,
+        parent: &str,
+        value: toml::Value,
+        options: &ParseOptions,
+    ) -> Result<(Theme, Vec<Warning>), Error> {
+        let mut base = self.load(&dir.join(parent))?;
+        merge(&mut base, value);
+        locate(Theme::from_value_with_options(base, options), &self.sources)
+    }
+
+    /// Loads the file at the given path, with all the files it extends merged.
+    fn load(&mut self, path: &Path) -> Result<toml::Value, Error> {
+        let source =
+            self.push(path).and_then(|()| Source::read(path).map_err(|e| self.error(e.into())));
+        if !self.files.iter().any(|f| f.path == path) {
+            let text = source.as_ref().ok().map(|s| s.text.clone());
+            self.files.push(File { path: path.to_owned(), text });
+        }
+        let source = source?;
+        let mut value = source.parse().map_err(|e| self.error(e.into()))?;
+        let parent = take_extends(&mut value).map_err(|e| self.error(source.locate(e).into()))?;
+        self.sources.push(source);
+        if let Some(parent) = parent {
+            let mut base = self.load(&parent_dir(path).join(parent))?;
+            merge(&mut base, value);
+            value = base;
+        }
+        self.stack.pop();
+        Ok(value)
+    }
+
+    /// Wraps an error in the file on the top of the stack.
+    fn error(&self, e: ErrorKind) -> Error {
+        let chain = self.stack.iter().map(|(p, _)| p.clone()).collect();
+        Error::new(ErrorKind::Load { chain, error: Box::new(e) })
+    }
+}
+
+/// Locates the invalid values or the problems in `sources`.
+///
+/// Values in inheriting files take precedence, so the located value is the one
+/// in the outermost file that contains the key.
+fn locate(
+    result: Result<(Theme, Vec<Warning>), Vec<ParseError>>,
+    sources: &[Source],
+) -> Result<(Theme, Vec<Warning>), Error> {
+    let sources = || sources.iter().map(|s| (s.path.as_deref(), &s.text));
+    match result {
+        Ok((theme, warnings)) => {
+            Ok((theme, warnings.into_iter().map(|w| w.locate_in(sources())).collect()))
+        }
+        Err(errors) => {
+            let mut errors: Vec<_> = errors.into_iter().map(|e| e.locate_in(sources())).collect();
+            // Report the errors in the order in which they appear in the files.
+            errors.sort_by_key(|e| {
+                let file = sources().position(|(path, _)| path == e.path());
+                (file.is_none(), file, e.line_col())
+            });
+            Err(Error::new(errors))
+        }
+    }
+}
+
+fn parent_dir(path: &Path) -> &Path {
+    path.parent().unwrap_or_else(|| Path::new(""))
+}
+
+fn take_extends(value: &mut toml::Value) -> Result<Option<String>, ParseError> {
+    match value.as_table_mut().and_then(|t| t.remove(EXTENDS)) {
+        None => Ok(None),
+        Some(toml::Value::String(path)) => Ok(Some(path)),
+        Some(v) => Err(ParseError::new(
+            format!("invalid type: {}, expected a path string", v.type_str()),
+            vec![EXTENDS.to_owned()],
+        )),
+    }
+}
+
+/// Deep-merges `overlay` into `base`. Values in `overlay` take precedence.
+///
+/// Only widget, style sheet and state tables are merged. Other values, such as
+/// colors, are replaced as a whole, since merging tables of different color
+/// forms (e.g., `{ l, c, h }` and `{ h, s, l }`) would produce another color.
+pub(crate) fn merge(base: &mut toml::Value, overlay: toml::Value) {
+    let (toml::Value::Table(base), toml::Value::Table(mut overlay)) = (base, overlay) else {
+        return;
+    };
+    if let Some(toml::Value::Table(colors)) = overlay.remove("color") {
+        match base.get_mut("color") {
+            Some(toml::Value::Table(base)) => {
+                // Color aliases are case-insensitive.
+                let shadowed: Vec<_> = base
+                    .keys()
+                    .filter(|k| colors.keys().any(|o| o.eq_ignore_ascii_case(k)))
+                    .cloned()
+                    .collect();
+                for k in shadowed {
+                    base.remove(&k);
+                }
+                base.extend(colors);
+            }
+            _ => {
+                base.insert("color".to_owned(), toml::Value::Table(colors));
+            }
+        }
+    }
+    merge_table(base, overlay, keys::KEYS);
+}
+
+fn merge_table(base: &mut toml::value::Table, overlay: toml::value::Table, keys: &[Key]) {
+    for (k, v) in overlay {
+        let kind = keys.iter().find(|key| key.name == k).map(|key| key.kind);
+        match (base.get_mut(&k), kind, v) {
+            (Some(toml::Value::Table(base)), Some(Kind::Table(keys)), toml::Value::Table(v)) => {
+                merge_table(base, v, keys);
+            }
+            (Some(toml::Value::Table(base)), Some(Kind::Map(keys)), toml::Value::Table(v)) => {
+                for (name, v) in v {
+                    match (base.get_mut(&name), v) {
+                        (Some(toml::Value::Table(base)), toml::Value::Table(v)) => {
+                            merge_table(base, v, keys);
+                        }
+                        (_, v) => {
+                            base.insert(name, v);
+                        }
+                    }
+                }
+            }
+            (_, _, v) => {
+                base.insert(k, v);
+            }
+        }
+    }
+}
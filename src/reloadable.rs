@@ -1,35 +1,529 @@
 #[cfg(not(target_family = "wasm"))]
-use std::path::PathBuf;
-use std::{fmt, ops, path::Path, str::FromStr};
+use std::{
+    collections::hash_map::DefaultHasher,
+    fs,
+    hash::{Hash, Hasher},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Instant,
+};
+use std::{
+    env, fmt,
+    io::{self, Read},
+    ops,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::{Duration, SystemTime},
+};
 
-use futures_util::stream;
 #[cfg(not(target_family = "wasm"))]
-use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use futures_channel::oneshot;
+use futures_util::{stream, StreamExt};
 #[cfg(not(target_family = "wasm"))]
-use tokio::sync::watch;
+use notify::{PollWatcher, RecursiveMode, Watcher};
 #[cfg(not(target_family = "wasm"))]
 use tracing::debug;
 
-use crate::{Error, Theme};
+#[cfg(not(target_family = "wasm"))]
+use crate::error::ErrorKind;
+use crate::{Error, ParseOptions, Theme, Warning};
+
+/// The default duration of [`ReloadableTheme::set_debounce`].
+#[cfg(not(target_family = "wasm"))]
+const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// A source that [`ReloadableTheme`] loads the theme from.
+///
+/// The following sources are provided by this crate:
+///
+/// - [`FileSource`]: a TOML file, watched for changes.
+/// - [`StringSource`]: a string of TOML text in memory, which can be replaced.
+/// - [`ReaderSource`]: a reader (e.g., a network stream) opened on each load.
+/// - [`EnvSource`]: an environment variable containing TOML text.
+///
+/// # Examples
+///
+/// A source that reads the theme from the settings store of an application:
+///
+/// ```rust
+/// use iced_style_config::{reloadable::ThemeSource, Error, ParseOptions, Theme, Warning};
+///
+/// # struct Settings;
+/// # impl Settings { fn get(&self, _: &str) -> String { String::new() } }
+/// #[derive(Debug)]
+/// struct SettingsSource {
+///     // ...
+/// }
+///
+/// impl ThemeSource for SettingsSource {
+///     fn load(&self, options: &ParseOptions) -> Result<(Theme, Vec<Warning>), Error> {
+///         # let settings = Settings;
+///         let text = settings.get("theme");
+///         Theme::from_str_with_options(&text, options)
+///     }
+///
+///     // Implement `changes` to reload the theme when the settings are updated.
+/// }
+/// ```
+pub trait ThemeSource: Send + Sync + 'static {
+    /// Loads the theme from this source.
+    fn load(&self, options: &ParseOptions) -> Result<(Theme, Vec<Warning>), Error>;
+
+    /// Returns a stream that yields an item each time the theme may have
+    /// changed.
+    ///
+    /// This is called once for each running subscription of
+    /// [`ReloadableTheme`]. The default implementation returns a stream that
+    /// never yields, for sources that are only reloaded by
+    /// [`ReloadableTheme::reload`].
+    fn changes(&self) -> iced_futures::BoxStream<()> {
+        Box::pin(stream::pending())
+    }
+
+    /// Returns the path of the file that this source loads the theme from, if
+    /// any.
+    fn path(&self) -> Option<&Path> {
+        None
+    }
+}
+
+/// Options for watching the file of a [`ReloadableTheme`].
+///
+/// By default, the file is watched using the native file system events of the
+/// platform (e.g., inotify on Linux), and falls back to polling if they are not
+/// available. Native events are not delivered on some file systems (e.g., NFS
+/// and Docker bind mounts); use [`poll`](Self::poll) on such file systems.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// use iced_style_config::{reloadable::WatchOptions, ParseOptions, ReloadableTheme};
+///
+/// # fn main() -> Result<(), iced_style_config::Error> {
+/// let watch_options = WatchOptions::new().poll(true).poll_interval(Duration::from_secs(2));
+/// let theme = ReloadableTheme::from_file_with_watch_options(
+///     "theme.toml",
+///     &ParseOptions::new(),
+///     &watch_options,
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct WatchOptions {
+    poll: bool,
+    poll_interval: Duration,
+    compare_contents: bool,
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        Self { poll: false, poll_interval: Duration::from_secs(1), compare_contents: false }
+    }
+}
+
+impl WatchOptions {
+    /// Creates options with the default values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Watches the file by polling instead of native file system events.
+    ///
+    /// The default is `false`.
+    #[must_use]
+    pub fn poll(mut self, yes: bool) -> Self {
+        self.poll = yes;
+        self
+    }
+
+    /// Sets the interval of polling.
+    ///
+    /// This is also used when falling back to polling. The default is 1 second.
+    #[must_use]
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Detects changes by comparing the content of the file instead of its
+    /// modification time when polling.
+    ///
+    /// This is useful on file systems where the modification time is
+    /// unreliable, at the cost of reading the file on every poll. The default
+    /// is `false`.
+    #[must_use]
+    pub fn compare_contents(mut self, yes: bool) -> Self {
+        self.compare_contents = yes;
+        self
+    }
+}
+
+/// A TOML file, watched for changes.
+///
+/// The files inherited via `extends` are also watched. The set of the watched
+/// files is updated each time the theme is loaded. If loading fails, the files
+/// read or attempted to be read are added to the set, so that, e.g., creating
+/// a missing inherited file reloads the theme.
+pub struct FileSource {
+    path: PathBuf,
+    #[cfg(not(target_family = "wasm"))]
+    options: WatchOptions,
+    #[cfg(not(target_family = "wasm"))]
+    watcher: Mutex<Watching>,
+    #[cfg(not(target_family = "wasm"))]
+    polling: bool,
+    #[cfg(not(target_family = "wasm"))]
+    shared: Arc<Shared>,
+}
+
+impl FileSource {
+    /// Begins watching the file at `path`.
+    #[cfg(not(target_family = "wasm"))]
+    #[cfg_attr(docsrs, doc(cfg(not(target_arch = "wasm32"))))] // rustdoc displays "non-WebAssembly" for `not(wasm32)` not `not(wasm)`.
+    pub fn new(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::with_options(path, &WatchOptions::default())
+    }
+
+    /// Begins watching the file at `path` with the given options.
+    #[cfg(not(target_family = "wasm"))]
+    #[cfg_attr(docsrs, doc(cfg(not(target_arch = "wasm32"))))] // rustdoc displays "non-WebAssembly" for `not(wasm32)` not `not(wasm)`.
+    pub fn with_options(path: impl AsRef<Path>, options: &WatchOptions) -> Result<Self, Error> {
+        let path = path.as_ref();
+        // Compute the hash before the theme is loaded so that changes during
+        // loading are not missed. The files inherited via `extends` are not
+        // known until the theme is loaded.
+        let text = fs::read_to_string(path)
+            .map_err(|error| Error::new(ErrorKind::Io { error, path: Some(path.to_owned()) }))?;
+        let hash = content_hash([Some(&*text)]);
+
+        let shared = Arc::new(Shared {
+            files: Mutex::new(vec![path.to_owned()]),
+            hash: Mutex::new(hash),
+            debounce: Mutex::new(DEFAULT_DEBOUNCE),
+            notifier: Notifier::default(),
+        });
+        let (event_sender, events) = mpsc::channel();
+        debug!("start watching {}", path.display());
+        let dir = watched_dir(path);
+        let (watcher, polling) = watch(dir, &event_sender, options)?;
+        let watcher = Mutex::new(Watching { watcher, dirs: vec![dir.to_owned()] });
+
+        let thread_shared = shared.clone();
+        thread::Builder::new()
+            .name("iced_style_config-watcher".to_owned())
+            .spawn(move || debounce(&events, &thread_shared))?;
+
+        Ok(Self { path: path.to_owned(), options: options.clone(), watcher, polling, shared })
+    }
+
+    /// Returns `true` if the file is watched by polling, either because
+    /// [`WatchOptions::poll`] is enabled or because native file system events
+    /// are not available.
+    pub fn is_polling(&self) -> bool {
+        #[cfg(not(target_family = "wasm"))]
+        return self.polling;
+        #[cfg(target_family = "wasm")]
+        false
+    }
+
+    /// Sets the duration to wait for after a change of the file before
+    /// notifying the change.
+    ///
+    /// See [`ReloadableTheme::set_debounce`] for details.
+    #[cfg(not(target_family = "wasm"))]
+    #[cfg_attr(docsrs, doc(cfg(not(target_arch = "wasm32"))))] // rustdoc displays "non-WebAssembly" for `not(wasm32)` not `not(wasm)`.
+    pub fn set_debounce(&self, debounce: Duration) {
+        *lock(&self.shared.debounce) = debounce;
+    }
+
+    /// Watches the directories containing `files`, and stops watching the
+    /// directories that no longer contain any of them.
+    ///
+    /// This also updates the hash of the contents of the files, so that the
+    /// next change is compared with the contents just loaded.
+    #[cfg(not(target_family = "wasm"))]
+    fn set_files(&self, files: Vec<crate::load::File>) {
+        let Watching { watcher, dirs: old_dirs } = &mut *lock(&self.watcher);
+        let mut dirs: Vec<PathBuf> = vec![];
+        for file in &files {
+            let file = &file.path;
+            let dir = watched_dir(file);
+            if dirs.iter().any(|d| d == dir) {
+                continue;
+            }
+            if !old_dirs.iter().any(|d| d == dir) {
+                debug!("start watching {}", file.display());
+                if let Err(e) = watcher.watch(dir, RecursiveMode::NonRecursive) {
+                    // Retry on the next load.
+                    debug!("failed to watch {}: {e}", dir.display());
+                    continue;
+                }
+            }
+            dirs.push(dir.to_owned());
+        }
+        for dir in &*old_dirs {
+            if !dirs.contains(dir) {
+                let _ = watcher.unwatch(dir);
+            }
+        }
+        *old_dirs = dirs;
+        *lock(&self.shared.hash) = content_hash(files.iter().map(|f| f.text.as_deref()));
+        *lock(&self.shared.files) = files.into_iter().map(|f| f.path).collect();
+    }
+}
+
+impl ThemeSource for FileSource {
+    fn load(&self, options: &ParseOptions) -> Result<(Theme, Vec<Warning>), Error> {
+        #[cfg(not(target_family = "wasm"))]
+        {
+            debug!("loading {}", self.path.display());
+            let mut files = vec![];
+            let result = crate::load::from_file_with_deps(&self.path, options, &mut files);
+            if result.is_err() {
+                // Keep watching the files of the last successful load in case
+                // the change is reverted. They were not read by this load, so
+                // read them for the hash.
+                let watched = lock(&self.shared.files).clone();
+                for path in watched {
+                    if !files.iter().any(|f| f.path == path) {
+                        let text = fs::read_to_string(&path).ok().map(Into::into);
+                        files.push(crate::load::File { path, text });
+                    }
+                }
+            }
+            self.set_files(files);
+            result
+        }
+        #[cfg(target_family = "wasm")]
+        Theme::from_file_with_options(&self.path, options)
+    }
+
+    fn changes(&self) -> iced_futures::BoxStream<()> {
+        #[cfg(not(target_family = "wasm"))]
+        return self.shared.notifier.subscribe();
+        #[cfg(target_family = "wasm")]
+        Box::pin(stream::pending())
+    }
+
+    fn path(&self) -> Option<&Path> {
+        Some(&self.path)
+    }
+}
+
+impl fmt::Debug for FileSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileSource")
+            .field("path", &self.path)
+            .field("polling", &self.is_polling())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A string of TOML text in memory.
+///
+/// The text can be replaced by [`set`](Self::set), which notifies the
+/// subscriptions of the [`ReloadableTheme`] (e.g., for a theme editor in the
+/// application).
+pub struct StringSource {
+    text: Mutex<String>,
+    notifier: Notifier,
+}
+
+impl StringSource {
+    /// Creates a new source from a string of TOML text.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self { text: Mutex::new(text.into()), notifier: Notifier::default() }
+    }
+
+    /// Replaces the text and notifies the change.
+    pub fn set(&self, text: impl Into<String>) {
+        *lock(&self.text) = text.into();
+        self.notifier.notify();
+    }
+}
+
+impl ThemeSource for StringSource {
+    fn load(&self, options: &ParseOptions) -> Result<(Theme, Vec<Warning>), Error> {
+        let text = lock(&self.text).clone();
+        Theme::from_str_with_options(&text, options)
+    }
+
+    fn changes(&self) -> iced_futures::BoxStream<()> {
+        self.notifier.subscribe()
+    }
+}
+
+impl fmt::Debug for StringSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StringSource").field("text", &self.text).finish_non_exhaustive()
+    }
+}
+
+/// A reader of TOML text, opened on each load.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::fs::File;
+///
+/// use iced_style_config::{reloadable::ReaderSource, ReloadableTheme};
+///
+/// # fn main() -> Result<(), iced_style_config::Error> {
+/// let theme = ReloadableTheme::new(ReaderSource::new(|| File::open("theme.toml")))?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ReaderSource<F> {
+    open: F,
+}
+
+impl<F> ReaderSource<F> {
+    /// Creates a new source from a function that opens the reader.
+    pub fn new(open: F) -> Self {
+        Self { open }
+    }
+}
+
+impl<F, R> ThemeSource for ReaderSource<F>
+where
+    F: Fn() -> io::Result<R> + Send + Sync + 'static,
+    R: Read,
+{
+    fn load(&self, options: &ParseOptions) -> Result<(Theme, Vec<Warning>), Error> {
+        let mut text = String::new();
+        (self.open)()?.read_to_string(&mut text)?;
+        Theme::from_str_with_options(&text, options)
+    }
+}
+
+impl<F> fmt::Debug for ReaderSource<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReaderSource").finish_non_exhaustive()
+    }
+}
+
+/// An environment variable containing TOML text.
+#[derive(Debug)]
+pub struct EnvSource {
+    name: String,
+}
+
+impl EnvSource {
+    /// Creates a new source from the name of an environment variable.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+impl ThemeSource for EnvSource {
+    fn load(&self, options: &ParseOptions) -> Result<(Theme, Vec<Warning>), Error> {
+        let text = env::var(&self.name).map_err(|e| {
+            let kind = match e {
+                env::VarError::NotPresent => io::ErrorKind::NotFound,
+                env::VarError::NotUnicode(_) => io::ErrorKind::InvalidData,
+            };
+            io::Error::new(kind, format!("environment variable `{}`: {e}", self.name))
+        })?;
+        Theme::from_str_with_options(&text, options)
+    }
+}
+
+/// An identifier of a [`ReloadableTheme`] that has a source.
+///
+/// This is used to tell which theme a [`ReloadEvent`] or [`ReloadOutcome`]
+/// belongs to when an application has multiple themes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ThemeId(u64);
+
+#[cfg(not(target_family = "wasm"))]
+impl ThemeId {
+    fn next() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+}
 
 #[derive(Debug, Clone)]
 #[non_exhaustive]
-pub struct ReloadEvent;
+pub struct ReloadEvent {
+    id: ThemeId,
+}
 
+impl ReloadEvent {
+    /// Returns the identifier of the theme whose source has changed.
+    ///
+    /// This is equal to the [`id`](ReloadableTheme::id) of the theme.
+    pub fn id(&self) -> ThemeId {
+        self.id
+    }
+}
+
+/// The result of reloading a theme in the background, published by
+/// [`ReloadableTheme::reload_subscription`].
+///
+/// Pass this to [`ReloadableTheme::apply`] to use the new theme.
 #[derive(Debug)]
-pub struct ReloadableTheme(Inner);
+#[non_exhaustive]
+pub struct ReloadOutcome {
+    /// The identifier of the theme that this outcome belongs to.
+    pub id: ThemeId,
+    /// The path of the file that was loaded, if the source is a file.
+    pub path: Option<PathBuf>,
+    /// The new theme and the warnings found during loading, or the error.
+    pub result: Result<(Theme, Vec<Warning>), Error>,
+    /// When the loading finished.
+    pub time: SystemTime,
+    /// How long the loading took.
+    pub elapsed: Duration,
+    generation: u64,
+}
 
-enum Inner {
-    #[cfg(not(target_family = "wasm"))]
-    Reloadable {
-        theme: Theme,
-        path: PathBuf,
-        watcher: RecommendedWatcher,
-        receiver: watch::Receiver<notify::Result<notify::Event>>,
-    },
-    Static {
-        theme: Theme,
-    },
+#[cfg(not(target_family = "wasm"))]
+impl ReloadOutcome {
+    fn new(
+        handle: &Handle<impl ThemeSource>,
+        result: Result<(Theme, Vec<Warning>), Error>,
+        elapsed: Duration,
+    ) -> Self {
+        Self {
+            id: handle.id,
+            path: handle.source.path().map(Path::to_owned),
+            result,
+            time: SystemTime::now(),
+            elapsed,
+            generation: handle.generation,
+        }
+    }
+}
+
+/// Iced style sheets that can be reloaded from a [`ThemeSource`].
+pub struct ReloadableTheme<S = FileSource> {
+    theme: Theme,
+    warnings: Vec<Warning>,
+    options: ParseOptions,
+    source: Option<(Handle<S>, Health)>,
+}
+
+/// A reference to the source of a theme, shared with subscriptions.
+struct Handle<S> {
+    id: ThemeId,
+    /// Incremented each time the source is replaced, to ignore the outcomes
+    /// of the previous source.
+    generation: u64,
+    source: Arc<S>,
+}
+
+impl<S> Clone for Handle<S> {
+    fn clone(&self) -> Self {
+        Self { id: self.id, generation: self.generation, source: self.source.clone() }
+    }
 }
 
 impl FromStr for ReloadableTheme {
@@ -37,7 +531,7 @@ impl FromStr for ReloadableTheme {
 
     /// Creates a new Iced style sheets from a string of TOML text.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self(Inner::Static { theme: s.parse()? }))
+        Ok(Self::from(s.parse::<Theme>()?))
     }
 }
 
@@ -49,28 +543,40 @@ impl ReloadableTheme {
     #[cfg(not(target_family = "wasm"))]
     #[cfg_attr(docsrs, doc(cfg(not(target_arch = "wasm32"))))] // rustdoc displays "non-WebAssembly" for `not(wasm32)` not `not(wasm)`.
     pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
-        let path = path.as_ref();
-        let theme = Theme::from_file(path)?;
-
-        let (sender, receiver) = watch::channel(Ok(notify::Event::new(notify::EventKind::Other)));
+        Self::from_file_with_options(path, &ParseOptions::default())
+    }
 
-        let mut watcher: RecommendedWatcher =
-            notify::recommended_watcher(move |res: notify::Result<_>| {
-                let _ = sender.send(res);
-            })
-            .map_err(Error::new)?;
-        debug!("start watching {}", path.display());
-        watcher.watch(path, RecursiveMode::NonRecursive).map_err(Error::new)?;
+    /// Creates a new Iced style sheets from a TOML file with the given options.
+    ///
+    /// The options are also used when the theme is reloaded, and the warnings
+    /// found during the last (re)load are available via
+    /// [`warnings`](Self::warnings).
+    #[cfg(not(target_family = "wasm"))]
+    #[cfg_attr(docsrs, doc(cfg(not(target_arch = "wasm32"))))] // rustdoc displays "non-WebAssembly" for `not(wasm32)` not `not(wasm)`.
+    pub fn from_file_with_options(
+        path: impl AsRef<Path>,
+        options: &ParseOptions,
+    ) -> Result<Self, Error> {
+        Self::from_file_with_watch_options(path, options, &WatchOptions::default())
+    }
 
-        Ok(Self(Inner::Reloadable { theme, path: path.to_owned(), watcher, receiver }))
+    /// Creates a new Iced style sheets from a TOML file with the given options
+    /// for parsing and watching the file.
+    #[cfg(not(target_family = "wasm"))]
+    #[cfg_attr(docsrs, doc(cfg(not(target_arch = "wasm32"))))] // rustdoc displays "non-WebAssembly" for `not(wasm32)` not `not(wasm)`.
+    pub fn from_file_with_watch_options(
+        path: impl AsRef<Path>,
+        options: &ParseOptions,
+        watch_options: &WatchOptions,
+    ) -> Result<Self, Error> {
+        Self::with_options(FileSource::with_options(path, watch_options)?, options)
     }
 
-    pub fn path(&self) -> Option<&Path> {
-        match &self.0 {
-            #[cfg(not(target_family = "wasm"))]
-            Inner::Reloadable { path, .. } => Some(path),
-            Inner::Static { .. } => None,
-        }
+    /// Returns `true` if the file is watched by polling, either because
+    /// [`WatchOptions::poll`] is enabled or because native file system events
+    /// are not available.
+    pub fn is_polling(&self) -> bool {
+        self.source().is_some_and(FileSource::is_polling)
     }
 
     /// Sets a new path and reloads the theme if necessary.
@@ -83,90 +589,449 @@ impl ReloadableTheme {
     #[cfg_attr(docsrs, doc(cfg(not(target_arch = "wasm32"))))] // rustdoc displays "non-WebAssembly" for `not(wasm32)` not `not(wasm)`.
     pub fn set_path(&mut self, new_path: impl AsRef<Path>) -> Result<(), Error> {
         let new_path = new_path.as_ref();
-        match &mut self.0 {
-            Inner::Reloadable { theme: old_theme, path: old_path, watcher, .. } => {
-                if new_path != *old_path {
-                    let new_theme = Theme::from_file(new_path)?;
-
-                    debug!("start watching {}", new_path.display());
-                    watcher.watch(new_path, RecursiveMode::NonRecursive).map_err(Error::new)?;
-                    let _ = watcher.unwatch(old_path);
+        if self.path() == Some(new_path) {
+            return Ok(());
+        }
 
-                    *old_path = new_path.to_owned();
-                    *old_theme = new_theme;
-                }
+        let source = match self.source() {
+            Some(old) => FileSource::with_options(new_path, &old.options).inspect(|source| {
+                source.set_debounce(*lock(&old.shared.debounce));
+            }),
+            None => FileSource::new(new_path),
+        };
+        let result = source.and_then(|source| Ok((source.load(&self.options)?, source)));
+        let ((theme, warnings), source) = match &mut self.source {
+            Some((_, health)) => health.record(result)?,
+            None => result?,
+        };
+        self.theme = theme;
+        self.warnings = warnings;
+        match &mut self.source {
+            Some((handle, _)) => {
+                handle.generation += 1;
+                handle.source = Arc::new(source);
             }
-            Inner::Static { .. } => *self = Self::from_file(new_path)?,
+            None => self.source = Some((Handle::new(source), Health::new())),
         }
 
         Ok(())
     }
 
+    /// Sets the duration to wait for after a change of the file before
+    /// publishing an event via [subscription](Self::subscription).
+    ///
+    /// Changes during this duration (e.g., multiple writes by a single save in
+    /// an editor) are coalesced into one event. Also, no event is published if
+    /// the content of the file is the same as the last time.
+    ///
+    /// The default is 100 milliseconds.
+    #[cfg(not(target_family = "wasm"))]
+    #[cfg_attr(docsrs, doc(cfg(not(target_arch = "wasm32"))))] // rustdoc displays "non-WebAssembly" for `not(wasm32)` not `not(wasm)`.
+    pub fn set_debounce(&mut self, debounce: Duration) {
+        if let Some(source) = self.source() {
+            source.set_debounce(debounce);
+        }
+    }
+}
+
+impl<S: ThemeSource> ReloadableTheme<S> {
+    /// Creates a new Iced style sheets from the given source.
+    ///
+    /// The theme is reloaded from the source when the source notifies a
+    /// change via [`ThemeSource::changes`], and events are published via
+    /// [subscription](Self::subscription).
+    #[cfg(not(target_family = "wasm"))]
+    #[cfg_attr(docsrs, doc(cfg(not(target_arch = "wasm32"))))] // rustdoc displays "non-WebAssembly" for `not(wasm32)` not `not(wasm)`.
+    pub fn new(source: S) -> Result<Self, Error> {
+        Self::with_options(source, &ParseOptions::default())
+    }
+
+    /// Creates a new Iced style sheets from the given source with the given
+    /// options.
+    ///
+    /// The options are also used when the theme is reloaded.
+    #[cfg(not(target_family = "wasm"))]
+    #[cfg_attr(docsrs, doc(cfg(not(target_arch = "wasm32"))))] // rustdoc displays "non-WebAssembly" for `not(wasm32)` not `not(wasm)`.
+    pub fn with_options(source: S, options: &ParseOptions) -> Result<Self, Error> {
+        let (theme, warnings) = source.load(options)?;
+        Ok(Self {
+            theme,
+            warnings,
+            options: options.clone(),
+            source: Some((Handle::new(source), Health::new())),
+        })
+    }
+
+    /// Returns the source of this theme.
+    ///
+    /// This is `None` if the theme is not loaded from a source (e.g., created
+    /// from a string by [`FromStr`]).
+    pub fn source(&self) -> Option<&S> {
+        self.source.as_ref().map(|(handle, _)| &*handle.source)
+    }
+
+    /// Returns the identifier of this theme, which is also attached to the
+    /// events published by [`subscription`](Self::subscription) and
+    /// [`reload_subscription`](Self::reload_subscription).
+    ///
+    /// This is `None` if the theme is not loaded from a source. Changing the
+    /// path by [`set_path`](ReloadableTheme::set_path) does not change the
+    /// identifier.
+    pub fn id(&self) -> Option<ThemeId> {
+        self.source.as_ref().map(|(handle, _)| handle.id)
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.source()?.path()
+    }
+
+    /// Returns the warnings found during the last (re)load of the theme.
+    ///
+    /// This is always empty if the theme is not loaded from a source.
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+
+    /// Returns the error of the last failed (re)load, if any.
+    ///
+    /// This is kept after a later successful reload; use
+    /// [`is_degraded`](Self::is_degraded) to check whether the last reload
+    /// failed.
+    pub fn last_error(&self) -> Option<&Error> {
+        self.source.as_ref()?.1.last_error.as_ref()
+    }
+
+    /// Returns when the theme was last loaded successfully.
+    ///
+    /// This is `None` if the theme is not loaded from a source.
+    pub fn last_success(&self) -> Option<SystemTime> {
+        self.source.as_ref().map(|(_, health)| health.last_success)
+    }
+
+    /// Returns the number of reloads that failed in a row since the last
+    /// successful (re)load.
+    pub fn consecutive_failures(&self) -> usize {
+        self.source.as_ref().map_or(0, |(_, health)| health.consecutive_failures)
+    }
+
+    /// Returns `true` if the last reload failed.
+    ///
+    /// In this case, the last valid theme is still used until the source is
+    /// fixed.
+    pub fn is_degraded(&self) -> bool {
+        self.consecutive_failures() != 0
+    }
+
     pub fn subscription(&self) -> iced_native::Subscription<ReloadEvent> {
-        let recipe = match &self.0 {
-            #[cfg(not(target_family = "wasm"))]
-            Inner::Reloadable { receiver, .. } => Recipe { receiver: Some(receiver.clone()) },
-            Inner::Static { .. } => Recipe { receiver: None },
-        };
-        iced_native::Subscription::from_recipe(recipe)
+        let handle = self.source.as_ref().map(|(handle, _)| handle.clone());
+        iced_native::Subscription::from_recipe(Recipe { handle })
+    }
+
+    /// Returns a subscription that reloads the theme in the background when
+    /// the source changes, and publishes the result.
+    ///
+    /// Unlike [`subscription`](Self::subscription), the source is read and
+    /// parsed on a background thread, so the application only needs to pass
+    /// the outcome to [`apply`](Self::apply) to switch to the new theme.
+    pub fn reload_subscription(&self) -> iced_native::Subscription<ReloadOutcome> {
+        let handle = self.source.as_ref().map(|(handle, _)| handle.clone());
+        iced_native::Subscription::from_recipe(ReloadRecipe {
+            handle,
+            options: self.options.clone(),
+        })
+    }
+
+    /// Switches to the theme loaded by
+    /// [`reload_subscription`](Self::reload_subscription).
+    ///
+    /// If the loading failed, returns the error and keeps the current theme.
+    /// The outcome is ignored if it is for another theme, or for a previous
+    /// source (e.g., the path was changed by
+    /// [`set_path`](ReloadableTheme::set_path) during the loading).
+    pub fn apply(&mut self, outcome: ReloadOutcome) -> Result<(), Error> {
+        match &mut self.source {
+            Some((handle, health))
+                if handle.id == outcome.id && handle.generation == outcome.generation =>
+            {
+                let (theme, warnings) = health.record(outcome.result)?;
+                self.theme = theme;
+                self.warnings = warnings;
+            }
+            _ => {
+                #[cfg(not(target_family = "wasm"))]
+                debug!("ignored a reload outcome of a previous source");
+            }
+        }
+        Ok(())
     }
 
     pub fn reload(&mut self) -> Result<(), Error> {
-        #[cfg(not(target_family = "wasm"))]
-        if let Self(Inner::Reloadable { theme, path, .. }) = self {
-            debug!("reloading {}", path.display());
-            let new = Theme::from_file(path)?;
-            *theme = new;
+        if let Some((handle, health)) = &mut self.source {
+            let (theme, warnings) = health.record(handle.source.load(&self.options))?;
+            self.theme = theme;
+            self.warnings = warnings;
         }
         Ok(())
     }
 }
 
-impl fmt::Debug for Inner {
+impl<S: fmt::Debug> fmt::Debug for ReloadableTheme<S> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            #[cfg(not(target_family = "wasm"))]
-            Inner::Reloadable { theme, path, .. } => {
-                f.debug_struct("Reloadable").field("theme", theme).field("path", path).finish()
-            }
-            Inner::Static { theme, .. } => f.debug_struct("Static").field("theme", theme).finish(),
+        let mut d = f.debug_struct("ReloadableTheme");
+        d.field("theme", &self.theme)
+            .field("warnings", &self.warnings)
+            .field("options", &self.options);
+        if let Some((handle, health)) = &self.source {
+            d.field("source", &handle.source)
+                .field("last_error", &health.last_error)
+                .field("consecutive_failures", &health.consecutive_failures);
         }
+        d.finish()
     }
 }
 
-impl ops::Deref for ReloadableTheme {
+impl<S> ops::Deref for ReloadableTheme<S> {
     type Target = Theme;
 
     fn deref(&self) -> &Self::Target {
-        match &self.0 {
-            #[cfg(not(target_family = "wasm"))]
-            Inner::Reloadable { theme, .. } => theme,
-            Inner::Static { theme, .. } => theme,
-        }
+        &self.theme
     }
 }
 
 impl From<Theme> for ReloadableTheme {
     fn from(theme: Theme) -> Self {
-        Self(Inner::Static { theme })
+        Self { theme, warnings: vec![], options: ParseOptions::default(), source: None }
     }
 }
 
 impl Default for ReloadableTheme {
     fn default() -> Self {
-        Self(Inner::Static { theme: Theme::default() })
+        Self::from(Theme::default())
     }
 }
 
-struct Recipe {
+#[cfg(not(target_family = "wasm"))]
+impl<S> Handle<S> {
+    fn new(source: S) -> Self {
+        Self { id: ThemeId::next(), generation: 0, source: Arc::new(source) }
+    }
+}
+
+/// The results of the recent reloads.
+struct Health {
+    last_error: Option<Error>,
+    last_success: SystemTime,
+    consecutive_failures: usize,
+}
+
+impl Health {
     #[cfg(not(target_family = "wasm"))]
-    receiver: Option<watch::Receiver<notify::Result<notify::Event>>>,
-    #[cfg(target_family = "wasm")]
-    receiver: Option<()>,
+    fn new() -> Self {
+        Self { last_error: None, last_success: SystemTime::now(), consecutive_failures: 0 }
+    }
+
+    fn succeeded(&mut self) {
+        self.last_success = SystemTime::now();
+        self.consecutive_failures = 0;
+    }
+
+    fn record<T>(&mut self, result: Result<T, Error>) -> Result<T, Error> {
+        match &result {
+            Ok(_) => self.succeeded(),
+            Err(e) => {
+                self.last_error = Some(e.clone());
+                self.consecutive_failures += 1;
+            }
+        }
+        result
+    }
+}
+
+/// A watcher and the directories it watches.
+#[cfg(not(target_family = "wasm"))]
+struct Watching {
+    /// Dropping this stops watching the files.
+    watcher: Box<dyn Watcher + Send + Sync>,
+    dirs: Vec<PathBuf>,
+}
+
+/// The state shared with the thread that debounces file events.
+#[cfg(not(target_family = "wasm"))]
+struct Shared {
+    /// The files read by the last successful load, from the outermost one,
+    /// followed by the files read or attempted to be read by failed loads
+    /// since then.
+    files: Mutex<Vec<PathBuf>>,
+    /// The hash of the contents of `files` when they were last loaded,
+    /// including which of them could not be read.
+    hash: Mutex<u64>,
+    debounce: Mutex<Duration>,
+    notifier: Notifier,
+}
+
+/// Locks the mutex, ignoring poisoning since the data is always valid.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Notifies changes to the streams returned by [`subscribe`](Self::subscribe).
+///
+/// This uses channels that do not depend on a specific async runtime, so
+/// subscriptions work with any executor of iced.
+#[derive(Default)]
+struct Notifier {
+    senders: Mutex<Vec<futures_channel::mpsc::Sender<()>>>,
 }
 
-impl<H, I> iced_futures::subscription::Recipe<H, I> for Recipe
+impl Notifier {
+    /// Returns a stream that yields an item for each change notified after
+    /// this call.
+    fn subscribe(&self) -> iced_futures::BoxStream<()> {
+        let (sender, receiver) = futures_channel::mpsc::channel(0);
+        lock(&self.senders).push(sender);
+        Box::pin(receiver)
+    }
+
+    fn notify(&self) {
+        // A full channel already has a pending notification, so changes are
+        // coalesced until the stream is polled. Drop the channels whose stream
+        // has been dropped.
+        lock(&self.senders).retain_mut(|sender| match sender.try_send(()) {
+            Ok(()) => true,
+            Err(e) => e.is_full(),
+        });
+    }
+}
+
+/// Creates a watcher that sends events to `sender` and starts watching `dir`.
+///
+/// Returns the watcher and whether it polls. If native file system events are
+/// not available, falls back to polling.
+#[cfg(not(target_family = "wasm"))]
+fn watch(
+    dir: &Path,
+    sender: &mpsc::Sender<notify::Result<notify::Event>>,
+    options: &WatchOptions,
+) -> Result<(Box<dyn Watcher + Send + Sync>, bool), Error> {
+    let handler = |sender: mpsc::Sender<_>| {
+        move |res: notify::Result<_>| {
+            let _ = sender.send(res);
+        }
+    };
+    if !options.poll {
+        let res = notify::recommended_watcher(handler(sender.clone())).and_then(|mut watcher| {
+            watcher.watch(dir, RecursiveMode::NonRecursive)?;
+            Ok(watcher)
+        });
+        match res {
+            Ok(watcher) => return Ok((Box::new(watcher), false)),
+            Err(e) => debug!("falling back to polling: {e}"),
+        }
+    }
+    let config = notify::Config::default()
+        .with_poll_interval(options.poll_interval)
+        .with_compare_contents(options.compare_contents);
+    let mut watcher = PollWatcher::new(handler(sender.clone()), config).map_err(Error::new)?;
+    watcher.watch(dir, RecursiveMode::NonRecursive).map_err(Error::new)?;
+    Ok((Box::new(watcher), true))
+}
+
+/// Returns the directory to watch to detect changes of the file at `path`.
+///
+/// Many editors save a file by writing a temporary file and renaming it to the
+/// original file, which replaces the file being watched. Watching the parent
+/// directory instead allows us to keep detecting changes of the file.
+#[cfg(not(target_family = "wasm"))]
+fn watched_dir(path: &Path) -> &Path {
+    match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    }
+}
+
+/// Returns `true` if the event may change the content of any of `files`.
+///
+/// Events for other files with the same name as one of `files` may also match,
+/// but they are filtered out later by comparing the content.
+#[cfg(not(target_family = "wasm"))]
+fn is_change(event: &notify::Result<notify::Event>, files: &[PathBuf]) -> bool {
+    match event {
+        Ok(event) => {
+            matches!(
+                event.kind,
+                notify::EventKind::Modify(..)
+                    | notify::EventKind::Create(..)
+                    | notify::EventKind::Remove(..)
+            ) && event.paths.iter().any(|p| files.iter().any(|f| p.file_name() == f.file_name()))
+        }
+        Err(e) => {
+            debug!("{e}");
+            false
+        }
+    }
+}
+
+/// Returns the hash of the contents of files, where `None` is a file that
+/// cannot be read, e.g., a missing file.
+#[cfg(not(target_family = "wasm"))]
+#[allow(single_use_lifetimes)] // anonymous lifetimes in `impl Trait` are unstable
+fn content_hash<'a>(texts: impl IntoIterator<Item = Option<&'a str>>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for text in texts {
+        text.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// Notifies the subscriptions of each burst of changes of the files.
+///
+/// This runs on a dedicated thread until the watcher is dropped.
+#[cfg(not(target_family = "wasm"))]
+fn debounce(events: &mpsc::Receiver<notify::Result<notify::Event>>, shared: &Shared) {
+    while let Ok(event) = events.recv() {
+        let files = lock(&shared.files).clone();
+        if !is_change(&event, &files) {
+            continue;
+        }
+        // Wait until the file is no longer changed.
+        let duration = *lock(&shared.debounce);
+        let mut deadline = Instant::now() + duration;
+        loop {
+            match events.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                Ok(event) => {
+                    if is_change(&event, &files) {
+                        deadline = Instant::now() + duration;
+                    }
+                }
+                Err(mpsc::RecvTimeoutError::Timeout) => break,
+                Err(mpsc::RecvTimeoutError::Disconnected) => return,
+            }
+        }
+        // Use the latest files in case they were updated by a load during the
+        // debouncing.
+        let files = lock(&shared.files).clone();
+        // A missing file is also part of the content, so that, e.g., fixing the
+        // root file reloads the theme while an inherited file is missing.
+        let texts: Vec<_> = files.iter().map(|f| fs::read_to_string(f).ok()).collect();
+        let new_hash = content_hash(texts.iter().map(Option::as_deref));
+        let mut hash = lock(&shared.hash);
+        if *hash == new_hash {
+            debug!("skip reloading {}: the content is unchanged", files[0].display());
+            continue;
+        }
+        *hash = new_hash;
+        drop(hash);
+        shared.notifier.notify();
+    }
+}
+
+struct Recipe<S> {
+    handle: Option<Handle<S>>,
+}
+
+impl<S, H, I> iced_futures::subscription::Recipe<H, I> for Recipe<S>
 where
+    S: ThemeSource,
     H: std::hash::Hasher,
 {
     type Output = ReloadEvent;
@@ -174,35 +1039,78 @@ where
     fn hash(&self, state: &mut H) {
         use std::hash::Hash;
         std::any::TypeId::of::<Self>().hash(state);
+        // Distinguish the subscriptions of multiple themes, which iced would
+        // otherwise deduplicate into one.
+        self.handle.as_ref().map(|h| (h.id, h.generation)).hash(state);
     }
 
     fn stream(
         self: Box<Self>,
         _input: iced_futures::BoxStream<I>,
     ) -> iced_futures::BoxStream<Self::Output> {
-        match self.receiver {
+        match self.handle {
             None => Box::pin(stream::pending()),
-            #[cfg(target_family = "wasm")]
-            Some(()) => unreachable!(),
+            Some(handle) => {
+                let id = handle.id;
+                Box::pin(handle.source.changes().map(move |()| ReloadEvent { id }))
+            }
+        }
+    }
+}
+
+struct ReloadRecipe<S> {
+    handle: Option<Handle<S>>,
+    options: ParseOptions,
+}
+
+impl<S, H, I> iced_futures::subscription::Recipe<H, I> for ReloadRecipe<S>
+where
+    S: ThemeSource,
+    H: std::hash::Hasher,
+{
+    type Output = ReloadOutcome;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+        std::any::TypeId::of::<Self>().hash(state);
+        self.handle.as_ref().map(|h| (h.id, h.generation)).hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: iced_futures::BoxStream<I>,
+    ) -> iced_futures::BoxStream<Self::Output> {
+        match self.handle {
             #[cfg(not(target_family = "wasm"))]
-            Some(mut receiver) => Box::pin(async_stream::stream! {
-                while receiver.changed().await.is_ok() {
-                    match &*receiver.borrow() {
-                        // TODO: Which event should we detect here?
-                        Ok(notify::Event { kind, .. })
-                            if matches!(
-                                kind,
-                                notify::EventKind::Modify(..) | notify::EventKind::Create(..)
-                            ) => {}
-                        Ok(_) => continue,
-                        Err(e) => {
-                            debug!("{e}");
+            Some(handle) => {
+                let options = self.options;
+                Box::pin(async_stream::stream! {
+                    let mut changes = handle.source.changes();
+                    while changes.next().await.is_some() {
+                        // Load the theme on a dedicated thread so that IO does
+                        // not block the executor.
+                        let (sender, outcome) = oneshot::channel();
+                        let (thread_handle, options) = (handle.clone(), options.clone());
+                        let spawned = thread::Builder::new()
+                            .name("iced_style_config-reload".to_owned())
+                            .spawn(move || {
+                                let start = Instant::now();
+                                let result = thread_handle.source.load(&options);
+                                let outcome =
+                                    ReloadOutcome::new(&thread_handle, result, start.elapsed());
+                                let _ = sender.send(outcome);
+                            });
+                        if let Err(e) = spawned {
+                            yield ReloadOutcome::new(&handle, Err(Error::new(e)), Duration::ZERO);
                             continue;
                         }
+                        if let Ok(outcome) = outcome.await {
+                            yield outcome;
+                        }
                     }
-                    yield ReloadEvent;
-                }
-            }),
+                })
+            }
+            _ => Box::pin(stream::pending()),
         }
     }
 }
@@ -0,0 +1,114 @@
+//! Locations of keys and values in TOML documents.
+
+use std::ops::Range;
+
+use serde::de::{self, MapAccess, SeqAccess};
+use toml::Spanned;
+
+use crate::import::*;
+
+/// The locations of the keys and values in a TOML document.
+#[derive(Debug, Default)]
+pub(crate) struct Spans(Vec<Entry>);
+
+#[derive(Debug)]
+struct Entry {
+    /// The key, or the index for array elements.
+    key: String,
+    /// The range of the key and the value, e.g., `key = "value"`.
+    span: Range<usize>,
+    children: Spans,
+}
+
+impl Spans {
+    /// Parses the locations of the keys and values in the given TOML text.
+    ///
+    /// Returns `None` if the text is not a valid TOML document.
+    pub(crate) fn parse(s: &str) -> Option<Self> {
+        toml::from_str(s).ok()
+    }
+
+    /// Returns the location of the value at the given key path.
+    ///
+    /// If the value does not exist in the document, returns the location of the
+    /// nearest parent that exists, along with the number of keys matched.
+    pub(crate) fn find(&self, key: &[String]) -> Option<(Range<usize>, usize)> {
+        let (first, rest) = key.split_first()?;
+        // A table can be defined in multiple places (e.g., `[button.primary]`
+        // and `[button.primary.active]`); prefer the one that contains the key.
+        let mut found = None;
+        for entry in self.0.iter().filter(|e| e.key == *first) {
+            let candidate = match entry.children.find(rest) {
+                Some((span, depth)) => (span, depth + 1),
+                None => (entry.span.clone(), 1),
+            };
+            if !matches!(&found, Some((_, depth)) if candidate.1 <= *depth) {
+                found = Some(candidate);
+            }
+        }
+        found
+    }
+}
+
+impl<'de> Deserialize<'de> for Spans {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Spans;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("any TOML value")
+            }
+
+            fn visit_bool<E>(self, _: bool) -> Result<Spans, E> {
+                Ok(Spans::default())
+            }
+
+            fn visit_i64<E>(self, _: i64) -> Result<Spans, E> {
+                Ok(Spans::default())
+            }
+
+            fn visit_f64<E>(self, _: f64) -> Result<Spans, E> {
+                Ok(Spans::default())
+            }
+
+            fn visit_str<E>(self, _: &str) -> Result<Spans, E> {
+                Ok(Spans::default())
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Spans, A::Error> {
+                let mut entries = vec![];
+                while let Some(key) = map.next_key::<Spanned<String>>()? {
+                    let value: Spanned<Spans> = map.next_value()?;
+                    // toml reports empty or incorrect spans for values of some
+                    // types (e.g., tables defined by headers and floats), so
+                    // only use the end of the value span when it looks valid.
+                    let end = if value.end() > key.end() { value.end() } else { key.end() };
+                    let span = key.start()..end;
+                    entries.push(Entry {
+                        key: key.into_inner(),
+                        span,
+                        children: value.into_inner(),
+                    });
+                }
+                Ok(Spans(entries))
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Spans, A::Error> {
+                let mut entries = vec![];
+                while let Some(value) = seq.next_element::<Spanned<Spans>>()? {
+                    let span = value.start()..value.end();
+                    let key = entries.len().to_string();
+                    entries.push(Entry { key, span, children: value.into_inner() });
+                }
+                Ok(Spans(entries))
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
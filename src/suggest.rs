@@ -0,0 +1,55 @@
+//! "Did you mean" suggestions for misspelled names.
+
+/// The text that precedes a suggestion in messages.
+pub(crate) const DID_YOU_MEAN: &str = ", did you mean ";
+
+/// Returns the candidate closest to `name`, if it is close enough to be a
+/// likely misspelling of `name`.
+///
+/// Names are compared case-insensitively.
+pub(crate) fn closest<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let name = name.to_ascii_lowercase();
+    // Allow one edit per three characters, so short names are never suggested.
+    let max = name.chars().count() / 3;
+    let mut best = None;
+    for candidate in candidates {
+        let d = distance(&name, &candidate.to_ascii_lowercase());
+        if d <= max && !matches!(best, Some((_, best)) if d >= best) {
+            best = Some((candidate, d));
+        }
+    }
+    best.map(|(candidate, _)| candidate)
+}
+
+/// Returns a suggestion to append to a message about `name`, e.g.,
+/// ", did you mean `hovered`?", or an empty string if no candidate is close
+/// enough.
+#[allow(single_use_lifetimes)] // anonymous lifetimes in `impl Trait` are unstable
+pub(crate) fn did_you_mean<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> String {
+    match closest(name, candidates) {
+        Some(candidate) => format!("{DID_YOU_MEAN}`{candidate}`?"),
+        None => String::new(),
+    }
+}
+
+/// Returns the Levenshtein distance between `a` and `b`.
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let cur = row[j + 1];
+            row[j + 1] = (prev + usize::from(ca != cb)).min(row[j] + 1).min(cur + 1);
+            prev = cur;
+        }
+    }
+    row[b.len()]
+}
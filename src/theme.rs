@@ -1,7 +1,7 @@
-use crate::import::*;
+use crate::{error::ParseError, import::*};
 
 /// An Iced style sheet.
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct Theme(ThemeInner);
 
 impl<'de> Deserialize<'de> for Theme {
@@ -11,120 +11,361 @@ impl<'de> Deserialize<'de> for Theme {
     {
         crate::color::COLOR_ALIASES.set(&RefCell::default(), || {
             let mut this = ThemeInner::deserialize(deserializer)?;
-            this.button.init();
-            this.checkbox.init();
-            this.container.init();
-            this.image.init();
-            this.image_viewer.init();
-            this.pick_list.init();
-            this.progress_bar.init();
-            #[cfg(feature = "qr_code")]
-            this.qr_code.init();
-            this.radio.init();
-            this.scrollable.init();
-            this.slider.init();
-            this.svg.init();
-            this.text.init();
-            this.text_input.init();
+            this.init();
             Ok(Self(this))
         })
     }
 }
 
-#[derive(Debug, Default, Deserialize)]
-#[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+impl Serialize for Theme {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 struct ThemeInner {
+    /// Path to the configuration file to inherit from, relative to this file.
+    ///
+    /// Style sheets and colors defined in this file are merged with those
+    /// defined in the inherited file, and take precedence over them.
+    // `extends` is resolved before deserialization, so this is only for the schema.
+    #[cfg(feature = "schema")]
+    #[serde(default, skip_serializing)]
+    extends: Option<String>,
     /// Colors defined in the style sheet.
+    #[serde(skip_serializing_if = "crate::color::ColorAliases::is_empty")]
     color: crate::color::ColorAliases,
     /// Style sheet for [`iced::Button`].
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "crate::button::Button::is_empty")]
     button: crate::button::Button,
     /// Style sheet for [`iced::Checkbox`].
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "crate::checkbox::Checkbox::is_empty")]
     checkbox: crate::checkbox::Checkbox,
     /// Style sheet for [`iced::Container`].
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "crate::container::Container::is_empty")]
     container: crate::container::Container,
     /// Style sheet for [`iced::Image`].
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "crate::image::Image::is_empty")]
     image: crate::image::Image,
     /// Style sheet for [`iced::image::Viewer`].
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "crate::image_viewer::Viewer::is_empty")]
     image_viewer: crate::image_viewer::Viewer,
     /// Style sheet for [`iced::PickList`].
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "crate::pick_list::PickList::is_empty")]
     pick_list: crate::pick_list::PickList,
     /// Style sheet for [`iced::ProgressBar`].
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "crate::progress_bar::ProgressBar::is_empty")]
     progress_bar: crate::progress_bar::ProgressBar,
     /// Style sheet for [`iced::QRCode`].
     #[cfg(feature = "qr_code")]
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "crate::qr_code::QRCode::is_empty")]
     qr_code: crate::qr_code::QRCode,
     /// Style sheet for [`iced::Radio`].
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "crate::radio::Radio::is_empty")]
     radio: crate::radio::Radio,
     // /// Style sheet for [`iced::Rule`].
     // #[serde(default)]
     // rule: crate::rule::Rule,
     /// Style sheet for [`iced::Scrollable`].
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "crate::scrollable::Scrollable::is_empty")]
     scrollable: crate::scrollable::Scrollable,
     /// Style sheet for [`iced::Slider`].
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "crate::slider::Slider::is_empty")]
     slider: crate::slider::Slider,
     /// Style sheet for [`iced::Svg`].
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "crate::svg::Svg::is_empty")]
     svg: crate::svg::Svg,
     /// Style sheet for [`iced::Text`].
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "crate::text::Text::is_empty")]
     text: crate::text::Text,
     /// Style sheets for [`iced::TextInput`].
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "crate::text_input::TextInput::is_empty")]
     text_input: crate::text_input::TextInput,
 }
 
+impl ThemeInner {
+    fn init(&mut self) {
+        self.button.init();
+        self.checkbox.init();
+        self.container.init();
+        self.image.init();
+        self.image_viewer.init();
+        self.pick_list.init();
+        self.progress_bar.init();
+        #[cfg(feature = "qr_code")]
+        self.qr_code.init();
+        self.radio.init();
+        self.scrollable.init();
+        self.slider.init();
+        self.svg.init();
+        self.text.init();
+        self.text_input.init();
+    }
+}
+
+impl fmt::Display for Theme {
+    /// Writes the style sheets as TOML text.
+    ///
+    /// Color aliases and CSS color functions (e.g., `"hsl(172deg 100% 72%)"`)
+    /// are written as in the configuration file. Other colors are written in
+    /// hex form (e.g., `"#6FFFE9"`), or as an array of floats if hex form
+    /// cannot represent them exactly. Only the fields that were set are
+    /// written. Parsing the text with [`from_str`](Self::from_str) gives an
+    /// equal [`Theme`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut value = toml::Value::try_from(self).map_err(|_| fmt::Error)?;
+        normalize_floats(&mut value);
+
+        // toml's serializer cannot write arrays that mix tables with other
+        // values (e.g., `rail_colors = ["ACTIVE", { rgb = "ACTIVE", a = 0.1 }]`),
+        // and toml's deserializer can only read enums from inline tables, so
+        // we write the text ourselves.
+        let mut sections = vec![];
+        let root = value.as_table().unwrap();
+        // Colors must be defined before they are referenced.
+        if let Some(toml::Value::Table(t)) = root.get("color") {
+            sections.push(("color".to_owned(), t));
+        }
+        for (k, v) in root {
+            match v {
+                _ if k == "color" => {}
+                toml::Value::Table(t) => {
+                    for (name, v) in t {
+                        if let toml::Value::Table(t) = v {
+                            sections.push((format!("{}.{}", Key(k), Key(name)), t));
+                        }
+                    }
+                }
+                _ => unreachable!(),
+            }
+        }
+        for (i, (path, table)) in sections.into_iter().enumerate() {
+            if i != 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "[{path}]")?;
+            write_entries(f, "", table)?;
+        }
+        Ok(())
+    }
+}
+
+/// Replaces floats with the shortest representation that round-trips as
+/// `f32`, e.g., `0.4399999976158142` with `0.44`.
+///
+/// All floats in the style sheets are `f32`, but `toml::Value` stores them as `f64`.
+fn normalize_floats(value: &mut toml::Value) {
+    match value {
+        #[allow(clippy::cast_possible_truncation)]
+        toml::Value::Float(f) => *f = (*f as f32).to_string().parse().unwrap(),
+        toml::Value::Array(a) => a.iter_mut().for_each(normalize_floats),
+        toml::Value::Table(t) => t.iter_mut().for_each(|(_, v)| normalize_floats(v)),
+        _ => {}
+    }
+}
+
+/// Writes the entries of a table using dotted keys, e.g., `active.background = "RED"`.
+///
+/// Tables that do not contain other tables (e.g., `{ rgb = "RED", a = 0.5 }`)
+/// and tables with a single entry (e.g., enums such as `{ units = 100 }`) are
+/// written as inline tables.
+fn write_entries(
+    f: &mut fmt::Formatter<'_>,
+    prefix: &str,
+    table: &toml::value::Table,
+) -> fmt::Result {
+    for (k, v) in table {
+        match v {
+            toml::Value::Table(t) if t.len() > 1 && t.values().any(toml::Value::is_table) => {
+                write_entries(f, &format!("{prefix}{}.", Key(k)), t)?;
+            }
+            _ => writeln!(f, "{prefix}{} = {}", Key(k), Inline(v))?,
+        }
+    }
+    Ok(())
+}
+
+struct Key<'a>(&'a str);
+
+impl fmt::Display for Key<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bare = |b: u8| b.is_ascii_alphanumeric() || b == b'_' || b == b'-';
+        if !self.0.is_empty() && self.0.bytes().all(bare) {
+            f.write_str(self.0)
+        } else {
+            write!(f, "{}", toml::Value::String(self.0.to_owned()))
+        }
+    }
+}
+
+struct Inline<'a>(&'a toml::Value);
+
+impl fmt::Display for Inline<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            toml::Value::Array(a) => {
+                f.write_str("[")?;
+                for (i, v) in a.iter().enumerate() {
+                    if i != 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{}", Inline(v))?;
+                }
+                f.write_str("]")
+            }
+            toml::Value::Table(t) => {
+                f.write_str("{")?;
+                for (i, (k, v)) in t.iter().enumerate() {
+                    f.write_str(if i == 0 { " " } else { ", " })?;
+                    write!(f, "{} = {}", Key(k), Inline(v))?;
+                }
+                f.write_str(" }")
+            }
+            v => write!(f, "{v}"),
+        }
+    }
+}
+
 impl FromStr for Theme {
     type Err = Error;
 
     /// Creates a new Iced style sheets from a string of TOML text.
     ///
-    /// This function is equivalent to the following code:
+    /// If the text does not contain `extends` key, this function creates the
+    /// same style sheets as `toml::from_str::<Theme>(s)` when that succeeds.
+    /// Unlike `toml::from_str`, this reports all the errors in the text, not
+    /// just the first one, and the `[color]` table may come after the values
+    /// that refer to its color aliases.
     ///
-    /// ```rust
-    /// use iced_style_config::Theme;
-    /// # fn from_str(s: &str) -> Result<Theme, Box<dyn std::error::Error>> {
-    /// let theme: Theme = toml::from_str(s)?;
-    /// Ok(theme)
-    /// # }
-    /// ```
+    /// The path specified by `extends` key is resolved relative to the current
+    /// directory, and the inherited files are merged as described in
+    /// [`Theme::merge`].
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        toml::from_str(s).map_err(Error::new)
+        crate::load::from_str(s, &ParseOptions::default()).map(|(theme, _)| theme)
     }
 }
 
 impl Theme {
     /// Creates a new Iced style sheets from a TOML file.
     ///
-    /// This function is equivalent to the following code:
+    /// This reads the file and parses it as [`Theme::from_str`] does, except
+    /// that the path specified by `extends` key is resolved relative to the
+    /// directory containing the file.
     ///
-    /// ```rust
-    /// use iced_style_config::Theme;
-    /// # use std::path::Path;
-    /// # fn from_file(path: impl AsRef<Path>) -> Result<Theme, Box<dyn std::error::Error>> {
-    /// let bytes = std::fs::read(path)?;
-    /// let theme: Theme = toml::from_slice(&bytes)?;
-    /// Ok(theme)
-    /// # }
-    /// ```
+    /// [`Error::path`] returns the path of a file that cannot be opened or
+    /// read. The message of such an error includes the chain of files that led
+    /// to the file if it is inherited via `extends`.
     pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
-        let bytes = fs::read(path).map_err(Error::new)?;
-        toml::from_slice(&bytes).map_err(Error::new)
+        crate::load::from_file(path.as_ref(), &ParseOptions::default()).map(|(theme, _)| theme)
+    }
+
+    /// Creates a new Iced style sheets from a string of TOML text with the
+    /// given options.
+    ///
+    /// Returns the warnings found during parsing along with the style sheets.
+    pub fn from_str_with_options(
+        s: &str,
+        options: &ParseOptions,
+    ) -> Result<(Self, Vec<Warning>), Error> {
+        crate::load::from_str(s, options)
+    }
+
+    /// Creates a new Iced style sheets from a TOML file with the given options.
+    ///
+    /// Returns the warnings found during parsing along with the style sheets.
+    pub fn from_file_with_options(
+        path: impl AsRef<Path>,
+        options: &ParseOptions,
+    ) -> Result<(Self, Vec<Warning>), Error> {
+        crate::load::from_file(path.as_ref(), options)
+    }
+
+    /// Creates a new Iced style sheets from a TOML value, and returns the
+    /// warnings found in the value.
+    pub(crate) fn from_value_with_options(
+        value: toml::Value,
+        options: &ParseOptions,
+    ) -> Result<(Self, Vec<Warning>), Vec<ParseError>> {
+        let mut errors = vec![];
+        if options.strict {
+            for unknown in crate::keys::unknown_keys(&value) {
+                errors.push(ParseError::unknown_key(&unknown));
+            }
+        }
+        let mut warnings = crate::lint::check(&value, options);
+        let theme = match Self::from_value(value) {
+            Ok(theme) if errors.is_empty() => theme,
+            Ok(_) => return Err(errors),
+            Err(e) => {
+                errors.extend(e);
+                return Err(errors);
+            }
+        };
+        if options.warn_shadowed_builtins {
+            for name in theme.0.color.shadowed_builtins() {
+                warnings.push(Warning::new(
+                    format!("color alias `{name}` shadows the built-in color alias"),
+                    vec!["color".to_owned(), name.to_owned()],
+                ));
+            }
+        }
+        Ok((theme, warnings))
+    }
+
+    /// Creates a new Iced style sheets from a TOML value.
+    ///
+    /// Unlike [`Deserialize::deserialize`], this does not depend on the order
+    /// of the keys: the colors are always resolved first. Also, this reports
+    /// all the errors in the value, not just the first one.
+    pub(crate) fn from_value(mut value: toml::Value) -> Result<Self, Vec<ParseError>> {
+        let color = value.as_table_mut().and_then(|t| t.remove("color"));
+        crate::color::COLOR_ALIASES.set(&RefCell::default(), || {
+            let (color, mut errors) = match color {
+                Some(color) => crate::color::ColorAliases::from_value(&color),
+                None => Default::default(),
+            };
+            let mut this = match deserialize_all(value, &mut errors) {
+                Some(this) if errors.is_empty() => this,
+                _ => return Err(errors),
+            };
+            this.color = color;
+            this.init();
+            Ok(Self(this))
+        })
+    }
+
+    /// Overlays `other` on this theme.
+    ///
+    /// Style sheets are merged field by field, e.g., setting
+    /// `button.primary.hovered.background` in `other` does not affect other
+    /// fields of `button.primary`. Values of fields, including colors, are
+    /// replaced as a whole. Color aliases defined in `other` replace
+    /// those defined in this theme, and all references to the aliases use the
+    /// new colors.
+    ///
+    /// If an error occurs, this theme is left unchanged.
+    pub fn merge(&mut self, other: &Self) -> Result<(), Error> {
+        let mut value = crate::builder::serialize(&*self);
+        crate::load::merge(&mut value, crate::builder::serialize(other));
+        *self = Self::from_value(value).map_err(Error::new)?;
+        Ok(())
+    }
+
+    /// Returns a builder to construct a theme in Rust code.
+    ///
+    /// See [`ThemeBuilder`](crate::ThemeBuilder) for details.
+    pub fn builder() -> crate::ThemeBuilder {
+        crate::ThemeBuilder::default()
     }
 
     /// Returns colors defined in the style sheet.
     pub fn color(&self) -> &crate::color::ColorMap {
-        &self.0.color.0
+        &self.0.color.map
     }
 
     /// Returns style sheet for [`iced::Button`].
@@ -219,14 +460,68 @@ mod schema {
             ThemeInner::json_schema(gen)
         }
     }
+}
 
-    impl Serialize for Theme {
-        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-        where
-            S: Serializer,
-        {
-            let _ = serializer;
-            unimplemented!()
+/// The color that replaces invalid values so that the following values can be
+/// checked.
+const PLACEHOLDER: &str = "#000000";
+
+/// Deserializes `value`, and records all the errors in it.
+///
+/// After each error, the invalid value is replaced with a placeholder color,
+/// or removed if the placeholder is also invalid there, and the value is
+/// deserialized again. Invalid values are replaced rather than removed so that
+/// arrays of colors keep their length. Valid values are deserialized in one
+/// pass.
+fn deserialize_all(mut value: toml::Value, errors: &mut Vec<ParseError>) -> Option<ThemeInner> {
+    let mut replaced: Vec<Vec<String>> = vec![];
+    let mut removed: Vec<Vec<String>> = vec![];
+    loop {
+        let e = match crate::value::from_value(&value) {
+            Ok(this) => return Some(this),
+            Err(e) => e,
+        };
+        let key = e.keys().to_vec();
+        // Errors in the placeholders, or in the tables and arrays that
+        // contained the changed values, are caused by the changes.
+        let caused = replaced.contains(&key)
+            || replaced.iter().chain(&removed).any(|k| k.len() > key.len() && k.starts_with(&key));
+        if !caused {
+            errors.push(e);
+            match get_mut(&mut value, &key) {
+                Some(v) if !key.is_empty() => *v = toml::Value::String(PLACEHOLDER.to_owned()),
+                _ => return None,
+            }
+            replaced.push(key);
+        } else if remove(&mut value, &key) {
+            removed.push(key);
+        } else {
+            return None;
         }
     }
 }
+
+/// Returns the value at the given key path.
+fn get_mut<'a>(value: &'a mut toml::Value, key: &[String]) -> Option<&'a mut toml::Value> {
+    key.iter().try_fold(value, |value, k| match value {
+        toml::Value::Table(table) => table.get_mut(k),
+        toml::Value::Array(array) => array.get_mut(k.parse::<usize>().ok()?),
+        _ => None,
+    })
+}
+
+/// Removes the value at the given key path, and returns whether it existed.
+fn remove(value: &mut toml::Value, key: &[String]) -> bool {
+    let Some((last, parent)) = key.split_last() else { return false };
+    match get_mut(value, parent) {
+        Some(toml::Value::Table(table)) => table.remove(last).is_some(),
+        Some(toml::Value::Array(array)) => match last.parse::<usize>() {
+            Ok(i) if i < array.len() => {
+                array.remove(i);
+                true
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
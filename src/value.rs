@@ -0,0 +1,148 @@
+//! Deserializing TOML values with the key paths of the invalid values.
+//!
+//! toml's `Value` deserializer reports the key path of an error only in its
+//! message, and loses it in flattened fields. This deserializer returns
+//! [`ParseError`], and prepends each key it passes through to the key path of
+//! the error.
+
+use serde::de::{
+    self, value::BorrowedStrDeserializer, value::MapAccessDeserializer, DeserializeSeed,
+    Error as _, IntoDeserializer, Unexpected, Visitor,
+};
+
+use crate::error::ParseError;
+
+/// Deserializes `T` from `value`.
+pub(crate) fn from_value<'de, T: de::Deserialize<'de>>(
+    value: &'de toml::Value,
+) -> Result<T, ParseError> {
+    T::deserialize(Deserializer(value))
+}
+
+struct Deserializer<'a>(&'a toml::Value);
+
+impl<'de> de::Deserializer<'de> for Deserializer<'de> {
+    type Error = ParseError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParseError> {
+        match self.0 {
+            toml::Value::String(v) => visitor.visit_borrowed_str(v),
+            toml::Value::Integer(v) => visitor.visit_i64(*v),
+            toml::Value::Float(v) => visitor.visit_f64(*v),
+            toml::Value::Boolean(v) => visitor.visit_bool(*v),
+            toml::Value::Datetime(_) => Err(ParseError::invalid_type(unexpected(self.0), &visitor)),
+            toml::Value::Array(array) => {
+                let mut seq = SeqAccess { iter: array.iter().enumerate() };
+                let value = visitor.visit_seq(&mut seq)?;
+                match seq.iter.len() {
+                    0 => Ok(value),
+                    _ => Err(ParseError::invalid_length(array.len(), &"fewer elements in array")),
+                }
+            }
+            toml::Value::Table(table) => {
+                visitor.visit_map(MapAccess { iter: table.iter(), value: None })
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParseError> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, ParseError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ParseError> {
+        match self.0 {
+            toml::Value::String(v) => visitor.visit_enum(v.as_str().into_deserializer()),
+            toml::Value::Table(table) if table.len() == 1 => {
+                visitor.visit_enum(MapAccessDeserializer::new(MapAccess {
+                    iter: table.iter(),
+                    value: None,
+                }))
+            }
+            _ => Err(ParseError::invalid_type(unexpected(self.0), &"string or table")),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+fn unexpected(value: &toml::Value) -> Unexpected<'_> {
+    match value {
+        toml::Value::String(v) => Unexpected::Str(v),
+        toml::Value::Integer(v) => Unexpected::Signed(*v),
+        toml::Value::Float(v) => Unexpected::Float(*v),
+        toml::Value::Boolean(v) => Unexpected::Bool(*v),
+        toml::Value::Datetime(_) => Unexpected::Other("datetime"),
+        toml::Value::Array(_) => Unexpected::Seq,
+        toml::Value::Table(_) => Unexpected::Map,
+    }
+}
+
+struct SeqAccess<'a> {
+    iter: std::iter::Enumerate<std::slice::Iter<'a, toml::Value>>,
+}
+
+impl<'de> de::SeqAccess<'de> for SeqAccess<'de> {
+    type Error = ParseError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, ParseError> {
+        let Some((i, value)) = self.iter.next() else { return Ok(None) };
+        seed.deserialize(Deserializer(value)).map(Some).map_err(|e| e.in_key(&i.to_string()))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+struct MapAccess<'a> {
+    iter: toml::map::Iter<'a>,
+    /// The entry whose key has been deserialized.
+    value: Option<(&'a str, &'a toml::Value)>,
+}
+
+impl<'de> de::MapAccess<'de> for MapAccess<'de> {
+    type Error = ParseError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, ParseError> {
+        let Some((key, value)) = self.iter.next() else { return Ok(None) };
+        self.value = Some((key, value));
+        seed.deserialize(BorrowedStrDeserializer::<ParseError>::new(key))
+            .map(Some)
+            .map_err(|e| e.in_key(key))
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, ParseError> {
+        let (key, value) = self.value.take().expect("next_value_seed called before next_key_seed");
+        seed.deserialize(Deserializer(value)).map_err(|e| e.in_key(key))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
@@ -0,0 +1,155 @@
+use std::{fmt, ops::Range, path::Path, sync::Arc};
+
+use crate::error::ParseError;
+
+/// Options for parsing configuration files.
+///
+/// # Examples
+///
+/// ```rust
+/// use iced_style_config::{ParseOptions, Theme};
+///
+/// # fn main() -> Result<(), iced_style_config::Error> {
+/// let options = ParseOptions::new().warn_shadowed_builtins(true);
+/// let (theme, warnings) = Theme::from_str_with_options("[color]\nRED = [0.9, 0.2, 0.2]", &options)?;
+/// assert_eq!(warnings.len(), 1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+#[allow(clippy::struct_excessive_bools)] // independent options
+pub struct ParseOptions {
+    pub(crate) warn_shadowed_builtins: bool,
+    pub(crate) warn_unknown_keys: bool,
+    pub(crate) warn_unused_colors: bool,
+    pub(crate) strict: bool,
+}
+
+impl ParseOptions {
+    /// Creates options with the default values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reports a warning when a color alias defined in the `[color]` table
+    /// shadows a built-in color alias (e.g., `RED`).
+    ///
+    /// The default is `false`.
+    #[must_use]
+    pub fn warn_shadowed_builtins(mut self, yes: bool) -> Self {
+        self.warn_shadowed_builtins = yes;
+        self
+    }
+
+    /// Reports a warning for each key that is not used by any widget (e.g.,
+    /// a misspelled `hoverd`), with a suggestion of the closest known key.
+    ///
+    /// Such keys are ignored during parsing. The default is `false`.
+    #[must_use]
+    pub fn warn_unknown_keys(mut self, yes: bool) -> Self {
+        self.warn_unknown_keys = yes;
+        self
+    }
+
+    /// Reports a warning for each color alias defined in the `[color]` table
+    /// that is not referred to by any other value in the configuration file.
+    ///
+    /// Color aliases can also be used from Rust code via
+    /// [`Theme::color`](crate::Theme::color), so this is not enabled by
+    /// default. The default is `false`.
+    #[must_use]
+    pub fn warn_unused_colors(mut self, yes: bool) -> Self {
+        self.warn_unused_colors = yes;
+        self
+    }
+
+    /// Reports an error for a key that is not used by any widget (e.g., an
+    /// unknown widget section, style key, or state name) instead of ignoring it.
+    ///
+    /// When this is enabled, [`warn_unknown_keys`](Self::warn_unknown_keys) has
+    /// no effect. The default is `false`.
+    #[must_use]
+    pub fn strict(mut self, yes: bool) -> Self {
+        self.strict = yes;
+        self
+    }
+}
+
+/// A non-fatal problem found during parsing the configuration file.
+///
+/// In addition to the warnings enabled by [`ParseOptions`], a style sheet
+/// named like `Default` is always reported, since it is never used as the
+/// default style sheet because style sheet names are case-sensitive. Other
+/// style sheets that are never used cannot be detected, because style sheets
+/// are looked up by name from Rust code.
+///
+/// There are no warnings for deprecated syntax, since no syntax of
+/// configuration files is deprecated: all the forms of colors, including hex
+/// strings of each component (e.g., `["0x6F", "0xFF", "0xE9"]`), remain
+/// supported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning(ParseError);
+
+impl Warning {
+    pub(crate) fn new(message: impl Into<String>, key: Vec<String>) -> Self {
+        Self(ParseError::new(message, key))
+    }
+
+    pub(crate) fn unknown_key(unknown: &crate::keys::UnknownKey) -> Self {
+        Self(ParseError::unknown_key(unknown))
+    }
+
+    /// Locates the problem in the text that contains the most keys of the key
+    /// path. See [`ParseError::locate_in`].
+    pub(crate) fn locate_in<'a>(
+        self,
+        sources: impl IntoIterator<Item = (Option<&'a Path>, &'a Arc<str>)>,
+    ) -> Self {
+        Self(self.0.locate_in(sources))
+    }
+
+    /// Returns the file that contains the problem, if known.
+    pub fn path(&self) -> Option<&Path> {
+        self.0.path()
+    }
+
+    /// Returns the dotted key path of the value that caused the problem, if
+    /// known.
+    pub fn key(&self) -> Option<String> {
+        self.0.key()
+    }
+
+    /// Returns the 1-based line and column of the problem, if known.
+    pub fn line_col(&self) -> Option<(usize, usize)> {
+        self.0.line_col()
+    }
+
+    /// Returns the byte range of the value that caused the problem in the
+    /// file, if known.
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.0.span()
+    }
+
+    /// Returns a value that displays this warning with an annotated snippet of
+    /// the configuration file, if the location of the problem is known.
+    ///
+    /// See [`Error::snippet`](crate::Error::snippet) for the format.
+    pub fn snippet(&self) -> impl fmt::Display + '_ {
+        Snippet(&self.0)
+    }
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+struct Snippet<'a>(&'a ParseError);
+
+impl fmt::Display for Snippet<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("warning: ")?;
+        self.0.write_snippet(f)
+    }
+}
@@ -2,8 +2,8 @@ use crate::import::*;
 
 widget_style_sheet_map!(Button);
 
-#[derive(Debug, Default, Deserialize)]
-#[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct StyleSheet {
     // layout
     width: Option<Length>,
@@ -11,8 +11,8 @@ pub struct StyleSheet {
     padding: Option<u16>,
 
     // style
-    #[serde(flatten)]
-    pub(crate) style: Option<Style>,
+    #[serde(flatten, deserialize_with = "flatten_option")]
+    style: Option<Resolved<Style, de::Style>>,
 }
 
 impl StyleSheet {
@@ -36,13 +36,48 @@ impl StyleSheet {
         if let Some(padding) = self.padding {
             this = this.padding(padding);
         }
-        if let Some(style) = self.style {
-            this = this.style(style);
+        if let Some(style) = &self.style {
+            this = this.style(style.style);
         }
         this
     }
 }
 
+builder! {
+    /// A builder for [`StyleSheet`].
+    StyleSheetBuilder {
+        values {
+            width: iced_native::Length,
+            height: iced_native::Length,
+            padding: u16,
+        }
+        colors {}
+        tables {
+            active: StyleBuilder,
+            hovered: StyleBuilder,
+            pressed: StyleBuilder,
+            disabled: StyleBuilder,
+        }
+    }
+}
+
+builder! {
+    /// A builder for the style of a button state.
+    StyleBuilder {
+        values {
+            shadow_offset: iced_native::Vector,
+            border_radius: f32,
+            border_width: f32,
+        }
+        colors {
+            background,
+            border_color,
+            text_color,
+        }
+        tables {}
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct Style {
     active: iced_style::button::Style,
@@ -72,21 +107,21 @@ impl iced_style::button::StyleSheet for Style {
 mod de {
     use crate::import::*;
 
-    #[derive(Debug, Deserialize)]
-    #[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+    #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+    #[cfg_attr(feature = "schema", derive(JsonSchema))]
     pub(super) struct Style {
-        #[serde(default)]
+        #[serde(default, skip_serializing_if = "is_default")]
         active: Inner,
-        #[serde(default)]
+        #[serde(default, skip_serializing_if = "is_default")]
         hovered: Inner,
-        #[serde(default)]
+        #[serde(default, skip_serializing_if = "is_default")]
         pressed: Inner,
-        #[serde(default)]
+        #[serde(default, skip_serializing_if = "is_default")]
         disabled: Inner,
     }
 
-    #[derive(Debug, Default, Deserialize)]
-    #[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+    #[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+    #[cfg_attr(feature = "schema", derive(JsonSchema))]
     struct Inner {
         shadow_offset: Option<Vector>,
         background: Option<Background>,
@@ -101,7 +136,7 @@ mod de {
             if let Some(shadow_offset) = self.shadow_offset {
                 style.shadow_offset = shadow_offset.into();
             }
-            if let Some(background) = self.background {
+            if let Some(background) = &self.background {
                 style.background = Some(background.into());
             }
             if let Some(border_radius) = self.border_radius {
@@ -110,22 +145,17 @@ mod de {
             if let Some(border_width) = self.border_width {
                 style.border_width = border_width;
             }
-            if let Some(border_color) = self.border_color {
+            if let Some(border_color) = &self.border_color {
                 style.border_color = border_color.into();
             }
-            if let Some(text_color) = self.text_color {
+            if let Some(text_color) = &self.text_color {
                 style.text_color = text_color.into();
             }
         }
     }
 
-    impl<'de> Deserialize<'de> for super::Style {
-        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-        where
-            D: Deserializer<'de>,
-        {
-            let input = Style::deserialize(deserializer)?;
-
+    impl From<&Style> for super::Style {
+        fn from(input: &Style) -> Self {
             let mut active = iced_style::button::Style::default();
             input.active.overwrite(&mut active);
 
@@ -159,7 +189,7 @@ mod de {
             };
             input.disabled.overwrite(&mut disabled);
 
-            Ok(Self { active, hovered, pressed, disabled })
+            Self { active, hovered, pressed, disabled }
         }
     }
 }
@@ -177,14 +207,4 @@ mod schema {
             de::Style::json_schema(gen)
         }
     }
-
-    impl Serialize for Style {
-        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-        where
-            S: Serializer,
-        {
-            let _ = serializer;
-            unimplemented!()
-        }
-    }
 }
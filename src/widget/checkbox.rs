@@ -2,8 +2,8 @@ use crate::import::*;
 
 widget_style_sheet_map!(Checkbox);
 
-#[derive(Debug, Deserialize, Default)]
-#[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct StyleSheet {
     // layout
     width: Option<Length>,
@@ -12,8 +12,8 @@ pub struct StyleSheet {
     text_size: Option<u16>,
 
     // style
-    #[serde(flatten)]
-    pub(crate) style: Option<Style>,
+    #[serde(flatten, deserialize_with = "flatten_option")]
+    style: Option<Resolved<Style, de::Style>>,
 }
 
 impl StyleSheet {
@@ -44,13 +44,66 @@ impl StyleSheet {
         if let Some(text_size) = self.text_size {
             this = this.text_size(text_size);
         }
-        if let Some(style) = self.style {
-            this = this.style(style);
+        if let Some(style) = &self.style {
+            this = this.style(style.style);
         }
         this
     }
 }
 
+builder! {
+    /// A builder for [`StyleSheet`].
+    StyleSheetBuilder {
+        values {
+            width: iced_native::Length,
+            size: u16,
+            spacing: u16,
+            text_size: u16,
+        }
+        colors {}
+        tables {
+            active: StyleBuilder,
+            hovered: StyleBuilder,
+        }
+    }
+}
+
+builder! {
+    /// A builder for the style of a checkbox state.
+    StyleBuilder {
+        values {
+            border_radius: f32,
+            border_width: f32,
+        }
+        colors {
+            background,
+            checkmark_color,
+            border_color,
+            text_color,
+        }
+        tables {
+            checked: CheckedStyleBuilder,
+        }
+    }
+}
+
+builder! {
+    /// A builder for the style of a checked checkbox.
+    CheckedStyleBuilder {
+        values {
+            border_radius: f32,
+            border_width: f32,
+        }
+        colors {
+            background,
+            checkmark_color,
+            border_color,
+            text_color,
+        }
+        tables {}
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct Style {
     active: iced_style::checkbox::Style,
@@ -80,26 +133,26 @@ impl iced_style::checkbox::StyleSheet for Style {
 mod de {
     use crate::import::*;
 
-    #[derive(Debug, Deserialize)]
-    #[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+    #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+    #[cfg_attr(feature = "schema", derive(JsonSchema))]
     pub(super) struct Style {
-        #[serde(default)]
+        #[serde(default, skip_serializing_if = "is_default")]
         active: Inner,
-        #[serde(default)]
+        #[serde(default, skip_serializing_if = "is_default")]
         hovered: Inner,
     }
 
-    #[derive(Debug, Default, Deserialize)]
-    #[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+    #[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+    #[cfg_attr(feature = "schema", derive(JsonSchema))]
     struct Inner {
-        #[serde(flatten)]
+        #[serde(flatten, deserialize_with = "flatten")]
         default: Inner2,
-        #[serde(default)]
+        #[serde(default, skip_serializing_if = "is_default")]
         checked: Inner2,
     }
 
-    #[derive(Debug, Default, Deserialize)]
-    #[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+    #[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+    #[cfg_attr(feature = "schema", derive(JsonSchema))]
     struct Inner2 {
         background: Option<Background>,
         checkmark_color: Option<Color>,
@@ -111,10 +164,10 @@ mod de {
 
     impl Inner2 {
         fn overwrite(&self, style: &mut iced_style::checkbox::Style) {
-            if let Some(background) = self.background {
+            if let Some(background) = &self.background {
                 style.background = background.into();
             }
-            if let Some(checkmark_color) = self.checkmark_color {
+            if let Some(checkmark_color) = &self.checkmark_color {
                 style.checkmark_color = checkmark_color.into();
             }
             if let Some(border_radius) = self.border_radius {
@@ -123,22 +176,17 @@ mod de {
             if let Some(border_width) = self.border_width {
                 style.border_width = border_width;
             }
-            if let Some(border_color) = self.border_color {
+            if let Some(border_color) = &self.border_color {
                 style.border_color = border_color.into();
             }
-            if let Some(text_color) = self.text_color {
+            if let Some(text_color) = &self.text_color {
                 style.text_color = Some(text_color.into());
             }
         }
     }
 
-    impl<'de> Deserialize<'de> for super::Style {
-        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-        where
-            D: Deserializer<'de>,
-        {
-            let input = Style::deserialize(deserializer)?;
-
+    impl From<&Style> for super::Style {
+        fn from(input: &Style) -> Self {
             let mut active = iced_style::checkbox::Style {
                 background: iced_native::Background::Color(iced_native::Color::from_rgb(
                     0.95, 0.95, 0.95,
@@ -169,7 +217,7 @@ mod de {
             input.hovered.default.overwrite(&mut hovered_checked);
             input.hovered.checked.overwrite(&mut hovered_checked);
 
-            Ok(Self { active, active_checked, hovered, hovered_checked })
+            Self { active, active_checked, hovered, hovered_checked }
         }
     }
 }
@@ -187,14 +235,4 @@ mod schema {
             de::Style::json_schema(gen)
         }
     }
-
-    impl Serialize for Style {
-        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-        where
-            S: Serializer,
-        {
-            let _ = serializer;
-            unimplemented!()
-        }
-    }
 }
@@ -2,8 +2,8 @@ use crate::import::*;
 
 widget_style_sheet_map!(Container);
 
-#[derive(Debug, Deserialize, Default)]
-#[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct StyleSheet {
     // layout
     padding: Option<u16>,
@@ -15,8 +15,8 @@ pub struct StyleSheet {
     align_y: Option<alignment::Vertical>,
 
     // style
-    #[serde(flatten)]
-    pub(crate) style: Option<Style>,
+    #[serde(flatten, deserialize_with = "flatten_option")]
+    style: Option<Resolved<Style, de::Style>>,
 }
 
 impl StyleSheet {
@@ -47,13 +47,36 @@ impl StyleSheet {
         if let Some(align_y) = self.align_y {
             this = this.align_y(align_y.into());
         }
-        if let Some(style) = self.style {
-            this = this.style(style);
+        if let Some(style) = &self.style {
+            this = this.style(style.style);
         }
         this
     }
 }
 
+builder! {
+    /// A builder for [`StyleSheet`].
+    StyleSheetBuilder {
+        values {
+            padding: u16,
+            width: iced_native::Length,
+            height: iced_native::Length,
+            max_width: u32,
+            max_height: u32,
+            align_x: iced_native::alignment::Horizontal,
+            align_y: iced_native::alignment::Vertical,
+            border_radius: f32,
+            border_width: f32,
+        }
+        colors {
+            text_color,
+            background,
+            border_color,
+        }
+        tables {}
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct Style(iced_style::container::Style);
 
@@ -66,8 +89,8 @@ impl iced_style::container::StyleSheet for Style {
 mod de {
     use crate::import::*;
 
-    #[derive(Debug, Deserialize)]
-    #[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+    #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+    #[cfg_attr(feature = "schema", derive(JsonSchema))]
     pub(super) struct Style {
         text_color: Option<Color>,
         background: Option<Background>,
@@ -76,18 +99,14 @@ mod de {
         border_color: Option<Color>,
     }
 
-    impl<'de> Deserialize<'de> for super::Style {
-        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-        where
-            D: Deserializer<'de>,
-        {
-            let input = Style::deserialize(deserializer)?;
+    impl From<&Style> for super::Style {
+        fn from(input: &Style) -> Self {
             let mut this = iced_style::container::Style::default();
 
-            if let Some(text_color) = input.text_color {
+            if let Some(text_color) = &input.text_color {
                 this.text_color = Some(text_color.into());
             }
-            if let Some(background) = input.background {
+            if let Some(background) = &input.background {
                 this.background = Some(background.into());
             }
             if let Some(border_radius) = input.border_radius {
@@ -96,11 +115,11 @@ mod de {
             if let Some(border_width) = input.border_width {
                 this.border_width = border_width;
             }
-            if let Some(border_color) = input.border_color {
+            if let Some(border_color) = &input.border_color {
                 this.border_color = border_color.into();
             }
 
-            Ok(Self(this))
+            Self(this)
         }
     }
 }
@@ -118,14 +137,4 @@ mod schema {
             de::Style::json_schema(gen)
         }
     }
-
-    impl Serialize for Style {
-        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-        where
-            S: Serializer,
-        {
-            let _ = serializer;
-            unimplemented!()
-        }
-    }
 }
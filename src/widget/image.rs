@@ -2,8 +2,8 @@ use crate::import::*;
 
 widget_style_sheet_map!(Image);
 
-#[derive(Debug, Deserialize, Default)]
-#[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct StyleSheet {
     // layout
     width: Option<Length>,
@@ -26,3 +26,15 @@ impl StyleSheet {
         this
     }
 }
+
+builder! {
+    /// A builder for [`StyleSheet`].
+    StyleSheetBuilder {
+        values {
+            width: iced_native::Length,
+            height: iced_native::Length,
+        }
+        colors {}
+        tables {}
+    }
+}
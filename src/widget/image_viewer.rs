@@ -2,8 +2,8 @@ use crate::import::*;
 
 widget_style_sheet_map!(Viewer);
 
-#[derive(Debug, Deserialize, Default)]
-#[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct StyleSheet {
     // layout
     padding: Option<u16>,
@@ -44,3 +44,19 @@ impl StyleSheet {
         this
     }
 }
+
+builder! {
+    /// A builder for [`StyleSheet`].
+    StyleSheetBuilder {
+        values {
+            padding: u16,
+            width: iced_native::Length,
+            height: iced_native::Length,
+            min_scale: f32,
+            max_scale: f32,
+            scale_step: f32,
+        }
+        colors {}
+        tables {}
+    }
+}
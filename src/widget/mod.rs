@@ -1,7 +1,7 @@
 macro_rules! widget_style_sheet_map {
     ($name:ident) => {
-        #[derive(Debug, Default, Deserialize)]
-        #[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+        #[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+        #[cfg_attr(feature = "schema", derive(JsonSchema))]
         #[serde(transparent)]
         pub struct $name(BTreeMap<String, StyleSheet>);
 
@@ -11,6 +11,27 @@ macro_rules! widget_style_sheet_map {
                     self.0.insert("default".to_string(), StyleSheet::default());
                 }
             }
+
+            /// Returns `true` if there are no style sheets other than the one
+            /// inserted by [`init`](Self::init).
+            pub(crate) fn is_empty(&self) -> bool {
+                self.style_sheets().next().is_none()
+            }
+
+            fn style_sheets(&self) -> impl Iterator<Item = (&String, &StyleSheet)> {
+                self.0.iter().filter(|&(name, style_sheet)| {
+                    name != "default" || *style_sheet != StyleSheet::default()
+                })
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.collect_map(self.style_sheets())
+            }
         }
 
         impl std::ops::Deref for $name {
@@ -35,6 +56,128 @@ macro_rules! widget_style_sheet_map {
     };
 }
 
+/// Defines a builder that writes a table of the configuration file.
+macro_rules! builder {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            values { $($value:ident: $ty:ty,)* }
+            colors { $($color:ident,)* }
+            tables { $($table:ident: $builder:ident,)* }
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Default)]
+        #[must_use]
+        pub struct $name(toml::value::Table);
+
+        impl $name {
+            $(
+                #[doc = concat!("Sets `", stringify!($value), "`.")]
+                pub fn $value(mut self, $value: $ty) -> Self {
+                    self.0.insert(stringify!($value).into(), $value.to_value());
+                    self
+                }
+            )*
+            $(
+                #[doc = concat!("Sets `", stringify!($color), "` to a color or the name of a color alias.")]
+                pub fn $color(mut self, $color: impl Into<ColorValue>) -> Self {
+                    self.0.insert(stringify!($color).into(), $color.into().to_value());
+                    self
+                }
+            )*
+            $(
+                #[doc = concat!("Sets `", stringify!($table), "` using the given builder.")]
+                pub fn $table(mut self, f: impl FnOnce($builder) -> $builder) -> Self {
+                    let builder = $builder::from_value(self.0.remove(stringify!($table)));
+                    self.0.insert(stringify!($table).into(), f(builder).into_value());
+                    self
+                }
+            )*
+
+            pub(crate) fn from_value(value: Option<toml::Value>) -> Self {
+                match value {
+                    Some(toml::Value::Table(table)) => Self(table),
+                    _ => Self::default(),
+                }
+            }
+
+            pub(crate) fn into_value(self) -> toml::Value {
+                toml::Value::Table(self.0)
+            }
+        }
+    };
+}
+
+use crate::import::*;
+
+/// A style resolved from the input in the configuration file.
+///
+/// The input is retained so that the style can be serialized as written.
+#[derive(Debug, Clone)]
+pub(crate) struct Resolved<S, I> {
+    pub(crate) style: S,
+    input: I,
+}
+
+impl<S, I> Resolved<S, I>
+where
+    S: for<'a> From<&'a I>,
+{
+    pub(crate) fn new(input: I) -> Self {
+        Self { style: S::from(&input), input }
+    }
+}
+
+impl<S, I> Default for Resolved<S, I>
+where
+    S: for<'a> From<&'a I>,
+    I: Default,
+{
+    fn default() -> Self {
+        Self::new(I::default())
+    }
+}
+
+impl<S, I: PartialEq> PartialEq for Resolved<S, I> {
+    fn eq(&self, other: &Self) -> bool {
+        self.input == other.input
+    }
+}
+
+impl<'de, S, I> Deserialize<'de> for Resolved<S, I>
+where
+    S: for<'a> From<&'a I>,
+    I: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        I::deserialize(deserializer).map(Self::new)
+    }
+}
+
+impl<S, I: Serialize> Serialize for Resolved<S, I> {
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: Serializer,
+    {
+        self.input.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "schema")]
+impl<S: JsonSchema, I> JsonSchema for Resolved<S, I> {
+    fn schema_name() -> String {
+        S::schema_name()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        S::json_schema(gen)
+    }
+}
+
 pub mod button;
 pub mod checkbox;
 pub mod container;
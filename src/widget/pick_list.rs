@@ -2,8 +2,8 @@ use crate::import::*;
 
 widget_style_sheet_map!(PickList);
 
-#[derive(Debug, Deserialize, Default)]
-#[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct StyleSheet {
     // layout
     padding: Option<u16>,
@@ -11,8 +11,8 @@ pub struct StyleSheet {
     text_size: Option<u16>,
 
     // style
-    #[serde(flatten)]
-    pub(crate) style: Option<Style>,
+    #[serde(flatten, deserialize_with = "flatten_option")]
+    style: Option<Resolved<Style, de::Style>>,
 }
 
 impl StyleSheet {
@@ -40,13 +40,65 @@ impl StyleSheet {
         if let Some(text_size) = self.text_size {
             this = this.text_size(text_size);
         }
-        if let Some(style) = self.style {
-            this = this.style(style);
+        if let Some(style) = &self.style {
+            this = this.style(style.style);
         }
         this
     }
 }
 
+builder! {
+    /// A builder for [`StyleSheet`].
+    StyleSheetBuilder {
+        values {
+            padding: u16,
+            width: iced_native::Length,
+            text_size: u16,
+        }
+        colors {}
+        tables {
+            menu: MenuBuilder,
+            active: StyleBuilder,
+            hovered: StyleBuilder,
+        }
+    }
+}
+
+builder! {
+    /// A builder for the style of the menu.
+    MenuBuilder {
+        values {
+            border_width: f32,
+        }
+        colors {
+            text_color,
+            background,
+            border_color,
+            selected_text_color,
+            selected_background,
+        }
+        tables {}
+    }
+}
+
+builder! {
+    /// A builder for the style of a pick list state.
+    StyleBuilder {
+        values {
+            border_radius: f32,
+            border_width: f32,
+            icon_size: f32,
+        }
+        colors {
+            text_color,
+            placeholder_color,
+            background,
+            border_color,
+        }
+        tables {}
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct Style {
     menu: iced_style::menu::Style,
@@ -71,19 +123,19 @@ impl iced_style::pick_list::StyleSheet for Style {
 mod de {
     use crate::import::*;
 
-    #[derive(Debug, Deserialize)]
-    #[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+    #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+    #[cfg_attr(feature = "schema", derive(JsonSchema))]
     pub(super) struct Style {
-        #[serde(default)]
-        menu: crate::menu::Style,
-        #[serde(default)]
+        #[serde(default, skip_serializing_if = "is_default")]
+        menu: crate::menu::Menu,
+        #[serde(default, skip_serializing_if = "is_default")]
         active: Inner,
-        #[serde(default)]
+        #[serde(default, skip_serializing_if = "is_default")]
         hovered: Inner,
     }
 
-    #[derive(Debug, Default, Deserialize)]
-    #[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+    #[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+    #[cfg_attr(feature = "schema", derive(JsonSchema))]
     struct Inner {
         text_color: Option<Color>,
         placeholder_color: Option<Color>,
@@ -96,13 +148,13 @@ mod de {
 
     impl Inner {
         fn overwrite(&self, style: &mut iced_style::pick_list::Style) {
-            if let Some(text_color) = self.text_color {
+            if let Some(text_color) = &self.text_color {
                 style.text_color = text_color.into();
             }
-            if let Some(placeholder_color) = self.placeholder_color {
+            if let Some(placeholder_color) = &self.placeholder_color {
                 style.placeholder_color = placeholder_color.into();
             }
-            if let Some(background) = self.background {
+            if let Some(background) = &self.background {
                 style.background = background.into();
             }
             if let Some(border_radius) = self.border_radius {
@@ -111,7 +163,7 @@ mod de {
             if let Some(border_width) = self.border_width {
                 style.border_width = border_width;
             }
-            if let Some(border_color) = self.border_color {
+            if let Some(border_color) = &self.border_color {
                 style.border_color = border_color.into();
             }
             if let Some(icon_size) = self.icon_size {
@@ -120,14 +172,9 @@ mod de {
         }
     }
 
-    impl<'de> Deserialize<'de> for super::Style {
-        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-        where
-            D: Deserializer<'de>,
-        {
-            let input = Style::deserialize(deserializer)?;
-
-            let menu = input.menu.into();
+    impl From<&Style> for super::Style {
+        fn from(input: &Style) -> Self {
+            let menu = input.menu.style.into();
 
             let mut active = iced_style::pick_list::Style {
                 text_color: iced_native::Color::BLACK,
@@ -144,7 +191,7 @@ mod de {
                 iced_style::pick_list::Style { border_color: iced_native::Color::BLACK, ..active };
             input.hovered.overwrite(&mut hovered);
 
-            Ok(Self { menu, active, hovered })
+            Self { menu, active, hovered }
         }
     }
 }
@@ -162,14 +209,4 @@ mod schema {
             de::Style::json_schema(gen)
         }
     }
-
-    impl Serialize for Style {
-        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-        where
-            S: Serializer,
-        {
-            let _ = serializer;
-            unimplemented!()
-        }
-    }
 }
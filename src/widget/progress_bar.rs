@@ -2,16 +2,16 @@ use crate::import::*;
 
 widget_style_sheet_map!(ProgressBar);
 
-#[derive(Debug, Deserialize, Default)]
-#[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct StyleSheet {
     // layout
     width: Option<Length>,
     height: Option<Length>,
 
     // style
-    #[serde(flatten)]
-    pub(crate) style: Option<Style>,
+    #[serde(flatten, deserialize_with = "flatten_option")]
+    style: Option<Resolved<Style, de::Style>>,
 }
 
 impl StyleSheet {
@@ -32,13 +32,29 @@ impl StyleSheet {
         if let Some(height) = self.height {
             this = this.height(height.into());
         }
-        if let Some(style) = self.style {
-            this = this.style(style);
+        if let Some(style) = &self.style {
+            this = this.style(style.style);
         }
         this
     }
 }
 
+builder! {
+    /// A builder for [`StyleSheet`].
+    StyleSheetBuilder {
+        values {
+            width: iced_native::Length,
+            height: iced_native::Length,
+            border_radius: f32,
+        }
+        colors {
+            background,
+            bar,
+        }
+        tables {}
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct Style(iced_style::progress_bar::Style);
 
@@ -51,20 +67,16 @@ impl iced_style::progress_bar::StyleSheet for Style {
 mod de {
     use crate::import::*;
 
-    #[derive(Debug, Deserialize)]
-    #[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+    #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+    #[cfg_attr(feature = "schema", derive(JsonSchema))]
     pub(super) struct Style {
         background: Option<Background>,
         bar: Option<Background>,
         border_radius: Option<f32>,
     }
 
-    impl<'de> Deserialize<'de> for super::Style {
-        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-        where
-            D: Deserializer<'de>,
-        {
-            let input = Style::deserialize(deserializer)?;
+    impl From<&Style> for super::Style {
+        fn from(input: &Style) -> Self {
             let mut this = iced_style::progress_bar::Style {
                 background: iced_native::Background::Color(iced_native::Color::from_rgb(
                     0.6, 0.6, 0.6,
@@ -73,17 +85,17 @@ mod de {
                 border_radius: 5.0,
             };
 
-            if let Some(background) = input.background {
+            if let Some(background) = &input.background {
                 this.background = background.into();
             }
-            if let Some(bar) = input.bar {
+            if let Some(bar) = &input.bar {
                 this.bar = bar.into();
             }
             if let Some(border_radius) = input.border_radius {
                 this.border_radius = border_radius;
             }
 
-            Ok(Self(this))
+            Self(this)
         }
     }
 }
@@ -101,14 +113,4 @@ mod schema {
             de::Style::json_schema(gen)
         }
     }
-
-    impl Serialize for Style {
-        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-        where
-            S: Serializer,
-        {
-            let _ = serializer;
-            unimplemented!()
-        }
-    }
 }
@@ -2,8 +2,8 @@ use crate::import::*;
 
 widget_style_sheet_map!(QRCode);
 
-#[derive(Debug, Deserialize, Default)]
-#[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct StyleSheet {
     // layout
     dark: Option<Color>,
@@ -20,8 +20,8 @@ impl StyleSheet {
         let mut this = iced_graphics::QRCode::new(state);
         if self.dark.is_some() || self.light.is_some() {
             this = this.color(
-                self.dark.map_or(iced_native::Color::BLACK, Into::into),
-                self.light.map_or(iced_native::Color::WHITE, Into::into),
+                self.dark.as_ref().map_or(iced_native::Color::BLACK, Into::into),
+                self.light.as_ref().map_or(iced_native::Color::WHITE, Into::into),
             );
         }
         if let Some(cell_size) = self.cell_size {
@@ -30,3 +30,17 @@ impl StyleSheet {
         this
     }
 }
+
+builder! {
+    /// A builder for [`StyleSheet`].
+    StyleSheetBuilder {
+        values {
+            cell_size: u16,
+        }
+        colors {
+            dark,
+            light,
+        }
+        tables {}
+    }
+}
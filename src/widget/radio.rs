@@ -2,8 +2,8 @@ use crate::import::*;
 
 widget_style_sheet_map!(Radio);
 
-#[derive(Debug, Deserialize, Default)]
-#[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct StyleSheet {
     // layout
     width: Option<Length>,
@@ -12,8 +12,8 @@ pub struct StyleSheet {
     text_size: Option<u16>,
 
     // style
-    #[serde(flatten)]
-    pub(crate) style: Option<Style>,
+    #[serde(flatten, deserialize_with = "flatten_option")]
+    style: Option<Resolved<Style, de::Style>>,
 }
 
 impl StyleSheet {
@@ -24,7 +24,7 @@ impl StyleSheet {
     ///   - the label of the [`Radio`] button
     ///   - the current selected value
     ///   - a function that will be called when the [`Radio`] is selected. It
-    ///   receives the value of the radio and must produce a `Message`.
+    ///     receives the value of the radio and must produce a `Message`.
     pub fn new<V, Message, Renderer: iced_native::text::Renderer>(
         &self,
         value: V,
@@ -49,13 +49,46 @@ impl StyleSheet {
         if let Some(text_size) = self.text_size {
             this = this.text_size(text_size);
         }
-        if let Some(style) = self.style {
-            this = this.style(style);
+        if let Some(style) = &self.style {
+            this = this.style(style.style);
         }
         this
     }
 }
 
+builder! {
+    /// A builder for [`StyleSheet`].
+    StyleSheetBuilder {
+        values {
+            width: iced_native::Length,
+            size: u16,
+            spacing: u16,
+            text_size: u16,
+        }
+        colors {}
+        tables {
+            active: StyleBuilder,
+            hovered: StyleBuilder,
+        }
+    }
+}
+
+builder! {
+    /// A builder for the style of a radio button state.
+    StyleBuilder {
+        values {
+            border_width: f32,
+        }
+        colors {
+            background,
+            dot_color,
+            border_color,
+            text_color,
+        }
+        tables {}
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct Style {
     active: iced_style::radio::Style,
@@ -75,17 +108,17 @@ impl iced_style::radio::StyleSheet for Style {
 mod de {
     use crate::import::*;
 
-    #[derive(Debug, Deserialize)]
-    #[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+    #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+    #[cfg_attr(feature = "schema", derive(JsonSchema))]
     pub(super) struct Style {
-        #[serde(default)]
+        #[serde(default, skip_serializing_if = "is_default")]
         active: Inner,
-        #[serde(default)]
+        #[serde(default, skip_serializing_if = "is_default")]
         hovered: Inner,
     }
 
-    #[derive(Debug, Default, Deserialize)]
-    #[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+    #[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+    #[cfg_attr(feature = "schema", derive(JsonSchema))]
     struct Inner {
         background: Option<Background>,
         dot_color: Option<Color>,
@@ -96,31 +129,26 @@ mod de {
 
     impl Inner {
         fn overwrite(&self, style: &mut iced_style::radio::Style) {
-            if let Some(background) = self.background {
+            if let Some(background) = &self.background {
                 style.background = background.into();
             }
-            if let Some(dot_color) = self.dot_color {
+            if let Some(dot_color) = &self.dot_color {
                 style.dot_color = dot_color.into();
             }
             if let Some(border_width) = self.border_width {
                 style.border_width = border_width;
             }
-            if let Some(border_color) = self.border_color {
+            if let Some(border_color) = &self.border_color {
                 style.border_color = border_color.into();
             }
-            if let Some(text_color) = self.text_color {
+            if let Some(text_color) = &self.text_color {
                 style.text_color = Some(text_color.into());
             }
         }
     }
 
-    impl<'de> Deserialize<'de> for super::Style {
-        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-        where
-            D: Deserializer<'de>,
-        {
-            let input = Style::deserialize(deserializer)?;
-
+    impl From<&Style> for super::Style {
+        fn from(input: &Style) -> Self {
             let mut active = iced_style::radio::Style {
                 background: iced_native::Background::Color(iced_native::Color::from_rgb(
                     0.95, 0.95, 0.95,
@@ -140,7 +168,7 @@ mod de {
             };
             input.hovered.overwrite(&mut hovered);
 
-            Ok(Self { active, hovered })
+            Self { active, hovered }
         }
     }
 }
@@ -158,14 +186,4 @@ mod schema {
             de::Style::json_schema(gen)
         }
     }
-
-    impl Serialize for Style {
-        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-        where
-            S: Serializer,
-        {
-            let _ = serializer;
-            unimplemented!()
-        }
-    }
 }
@@ -2,8 +2,8 @@ use crate::import::*;
 
 widget_style_sheet_map!(Scrollable);
 
-#[derive(Debug, Deserialize, Default)]
-#[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct StyleSheet {
     // layout
     spacing: Option<u16>,
@@ -18,8 +18,8 @@ pub struct StyleSheet {
     scroller_width: Option<u16>,
 
     // style
-    #[serde(flatten)]
-    pub(crate) style: Option<Style>,
+    #[serde(flatten, deserialize_with = "flatten_option")]
+    style: Option<Resolved<Style, de::Style>>,
 }
 
 impl StyleSheet {
@@ -59,13 +59,69 @@ impl StyleSheet {
         if let Some(scroller_width) = self.scroller_width {
             this = this.scroller_width(scroller_width);
         }
-        if let Some(style) = self.style {
-            this = this.style(style);
+        if let Some(style) = &self.style {
+            this = this.style(style.style);
         }
         this
     }
 }
 
+builder! {
+    /// A builder for [`StyleSheet`].
+    StyleSheetBuilder {
+        values {
+            spacing: u16,
+            padding: u16,
+            width: iced_native::Length,
+            height: iced_native::Length,
+            max_width: u32,
+            max_height: u32,
+            align_items: iced_native::Alignment,
+            scrollbar_width: u16,
+            scrollbar_margin: u16,
+            scroller_width: u16,
+        }
+        colors {}
+        tables {
+            active: ScrollbarBuilder,
+            hovered: ScrollbarBuilder,
+            dragging: ScrollbarBuilder,
+        }
+    }
+}
+
+builder! {
+    /// A builder for the style of a scrollbar state.
+    ScrollbarBuilder {
+        values {
+            border_radius: f32,
+            border_width: f32,
+        }
+        colors {
+            background,
+            border_color,
+        }
+        tables {
+            scroller: ScrollerBuilder,
+        }
+    }
+}
+
+builder! {
+    /// A builder for the style of the scroller.
+    ScrollerBuilder {
+        values {
+            border_radius: f32,
+            border_width: f32,
+        }
+        colors {
+            color,
+            border_color,
+        }
+        tables {}
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct Style {
     active: iced_style::scrollable::Scrollbar,
@@ -90,19 +146,19 @@ impl iced_style::scrollable::StyleSheet for Style {
 mod de {
     use crate::import::*;
 
-    #[derive(Debug, Clone, Copy, Default, Deserialize)]
-    #[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+    #[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+    #[cfg_attr(feature = "schema", derive(JsonSchema))]
     pub(super) struct Style {
-        #[serde(default)]
+        #[serde(default, skip_serializing_if = "is_default")]
         active: Scrollbar,
-        #[serde(default)]
+        #[serde(default, skip_serializing_if = "is_default")]
         hovered: Scrollbar,
-        #[serde(default)]
+        #[serde(default, skip_serializing_if = "is_default")]
         dragging: Scrollbar,
     }
 
-    #[derive(Debug, Clone, Copy, Default, Deserialize)]
-    #[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+    #[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+    #[cfg_attr(feature = "schema", derive(JsonSchema))]
     struct Scrollbar {
         background: Option<Background>,
         border_radius: Option<f32>,
@@ -113,7 +169,7 @@ mod de {
 
     impl Scrollbar {
         fn overwrite(&self, scrollbar: &mut iced_style::scrollable::Scrollbar) {
-            if let Some(background) = self.background {
+            if let Some(background) = &self.background {
                 scrollbar.background = Some(background.into());
             }
             if let Some(border_radius) = self.border_radius {
@@ -122,11 +178,11 @@ mod de {
             if let Some(border_width) = self.border_width {
                 scrollbar.border_width = border_width;
             }
-            if let Some(border_color) = self.border_color {
+            if let Some(border_color) = &self.border_color {
                 scrollbar.border_color = border_color.into();
             }
             if let Some(scroller) = &self.scroller {
-                if let Some(color) = scroller.color {
+                if let Some(color) = &scroller.color {
                     scrollbar.scroller.color = color.into();
                 }
                 if let Some(border_radius) = scroller.border_radius {
@@ -135,15 +191,15 @@ mod de {
                 if let Some(border_width) = scroller.border_width {
                     scrollbar.scroller.border_width = border_width;
                 }
-                if let Some(border_color) = scroller.border_color {
+                if let Some(border_color) = &scroller.border_color {
                     scrollbar.scroller.border_color = border_color.into();
                 }
             }
         }
     }
 
-    #[derive(Debug, Clone, Copy, Default, Deserialize)]
-    #[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+    #[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+    #[cfg_attr(feature = "schema", derive(JsonSchema))]
     struct Scroller {
         color: Option<Color>,
         border_radius: Option<f32>,
@@ -151,13 +207,8 @@ mod de {
         border_color: Option<Color>,
     }
 
-    impl<'de> Deserialize<'de> for super::Style {
-        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-        where
-            D: Deserializer<'de>,
-        {
-            let input = Style::deserialize(deserializer)?;
-
+    impl From<&Style> for super::Style {
+        fn from(input: &Style) -> Self {
             let mut active = iced_style::scrollable::Scrollbar {
                 background: None,
                 border_radius: 5.0,
@@ -181,7 +232,7 @@ mod de {
             let mut dragging = hovered;
             input.dragging.overwrite(&mut dragging);
 
-            Ok(Self { active, hovered, dragging })
+            Self { active, hovered, dragging }
         }
     }
 }
@@ -199,14 +250,4 @@ mod schema {
             de::Style::json_schema(gen)
         }
     }
-
-    impl Serialize for Style {
-        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-        where
-            S: Serializer,
-        {
-            let _ = serializer;
-            unimplemented!()
-        }
-    }
 }
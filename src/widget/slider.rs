@@ -2,16 +2,16 @@ use crate::import::*;
 
 widget_style_sheet_map!(Slider);
 
-#[derive(Debug, Deserialize, Default)]
-#[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct StyleSheet {
     // layout
     width: Option<Length>,
     height: Option<u16>,
 
     // style
-    #[serde(flatten)]
-    pub(crate) style: Option<Style>,
+    #[serde(flatten, deserialize_with = "flatten_option")]
+    style: Option<Resolved<Style, de::Style>>,
 }
 
 impl StyleSheet {
@@ -22,8 +22,8 @@ impl StyleSheet {
     ///   - an inclusive range of possible values
     ///   - the current value of the [`iced::Slider`]
     ///   - a function that will be called when the [`iced::Slider`] is dragged.
-    ///   It receives the new value of the [`iced::Slider`] and must produce a
-    ///   `Message`.
+    ///     It receives the new value of the [`iced::Slider`] and must produce a
+    ///     `Message`.
     pub fn new<'a, T, Message>(
         &self,
         state: &'a mut iced_native::widget::slider::State,
@@ -42,13 +42,68 @@ impl StyleSheet {
         if let Some(height) = self.height {
             this = this.height(height);
         }
-        if let Some(style) = self.style {
-            this = this.style(style);
+        if let Some(style) = &self.style {
+            this = this.style(style.style);
         }
         this
     }
 }
 
+builder! {
+    /// A builder for [`StyleSheet`].
+    StyleSheetBuilder {
+        values {
+            width: iced_native::Length,
+            height: u16,
+        }
+        colors {}
+        tables {
+            active: StyleBuilder,
+            hovered: StyleBuilder,
+            dragging: StyleBuilder,
+        }
+    }
+}
+
+builder! {
+    /// A builder for the style of a slider state.
+    StyleBuilder {
+        values {}
+        colors {}
+        tables {
+            handle: HandleBuilder,
+        }
+    }
+}
+
+impl StyleBuilder {
+    /// Sets `rail_colors` to colors or names of color aliases.
+    pub fn rail_colors(
+        mut self,
+        first: impl Into<ColorValue>,
+        second: impl Into<ColorValue>,
+    ) -> Self {
+        let colors = vec![first.into().to_value(), second.into().to_value()];
+        self.0.insert("rail_colors".into(), toml::Value::Array(colors));
+        self
+    }
+}
+
+builder! {
+    /// A builder for the style of the handle.
+    HandleBuilder {
+        values {
+            shape: iced_native::widget::slider::HandleShape,
+            border_width: f32,
+        }
+        colors {
+            color,
+            border_color,
+        }
+        tables {}
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct Style {
     active: iced_style::slider::Style,
@@ -73,19 +128,19 @@ impl iced_style::slider::StyleSheet for Style {
 mod de {
     use crate::import::*;
 
-    #[derive(Debug, Default, Deserialize)]
-    #[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+    #[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+    #[cfg_attr(feature = "schema", derive(JsonSchema))]
     pub(super) struct Style {
-        #[serde(default)]
+        #[serde(default, skip_serializing_if = "is_default")]
         active: Inner,
-        #[serde(default)]
+        #[serde(default, skip_serializing_if = "is_default")]
         hovered: Inner,
-        #[serde(default)]
+        #[serde(default, skip_serializing_if = "is_default")]
         dragging: Inner,
     }
 
-    #[derive(Debug, Default, Deserialize)]
-    #[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+    #[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+    #[cfg_attr(feature = "schema", derive(JsonSchema))]
     struct Inner {
         rail_colors: Option<(Color, Color)>,
         handle: Option<Handle>,
@@ -93,17 +148,17 @@ mod de {
 
     impl Inner {
         fn overwrite(&self, style: &mut iced_style::slider::Style) {
-            if let Some(rail_colors) = self.rail_colors {
-                style.rail_colors = (rail_colors.0.into(), rail_colors.1.into());
+            if let Some((rail_color_0, rail_color_1)) = &self.rail_colors {
+                style.rail_colors = (rail_color_0.into(), rail_color_1.into());
             }
             if let Some(handle) = &self.handle {
                 if let Some(shape) = handle.shape {
                     style.handle.shape = shape.into();
                 }
-                if let Some(color) = handle.color {
+                if let Some(color) = &handle.color {
                     style.handle.color = color.into();
                 }
-                if let Some(border_color) = handle.border_color {
+                if let Some(border_color) = &handle.border_color {
                     style.handle.border_color = border_color.into();
                 }
                 if let Some(border_width) = handle.border_width {
@@ -113,8 +168,8 @@ mod de {
         }
     }
 
-    #[derive(Debug, Default, Deserialize)]
-    #[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+    #[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+    #[cfg_attr(feature = "schema", derive(JsonSchema))]
     struct Handle {
         shape: Option<HandleShape>,
         color: Option<Color>,
@@ -122,14 +177,59 @@ mod de {
         border_color: Option<Color>,
     }
 
-    #[derive(Debug, Clone, Copy, Deserialize)]
-    #[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
-    #[serde(rename_all = "snake_case")]
+    #[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+    #[cfg_attr(feature = "schema", derive(JsonSchema))]
+    #[serde(remote = "Self", rename_all = "snake_case")]
     enum HandleShape {
         Circle { radius: f32 },
         Rectangle { width: u16, border_radius: f32 },
     }
 
+    impl<'de> DeserializeEnum<'de> for HandleShape {
+        fn deserialize_derived<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Self::deserialize(deserializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for HandleShape {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize_enum(deserializer)
+        }
+    }
+
+    // toml cannot serialize struct variants, so write them as single-key tables.
+    impl Serialize for HandleShape {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            #[derive(Serialize)]
+            struct Circle {
+                radius: f32,
+            }
+            #[derive(Serialize)]
+            struct Rectangle {
+                width: u16,
+                border_radius: f32,
+            }
+
+            let mut map = serializer.serialize_map(Some(1))?;
+            match *self {
+                Self::Circle { radius } => map.serialize_entry("circle", &Circle { radius })?,
+                Self::Rectangle { width, border_radius } => {
+                    map.serialize_entry("rectangle", &Rectangle { width, border_radius })?;
+                }
+            }
+            map.end()
+        }
+    }
+
     impl From<HandleShape> for iced_native::widget::slider::HandleShape {
         fn from(shape: HandleShape) -> Self {
             match shape {
@@ -141,13 +241,19 @@ mod de {
         }
     }
 
-    impl<'de> Deserialize<'de> for super::Style {
-        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-        where
-            D: Deserializer<'de>,
-        {
-            let input = Style::deserialize(deserializer)?;
+    impl ToValue for iced_native::widget::slider::HandleShape {
+        fn to_value(self) -> toml::Value {
+            serialize(match self {
+                Self::Circle { radius } => HandleShape::Circle { radius },
+                Self::Rectangle { width, border_radius } => {
+                    HandleShape::Rectangle { width, border_radius }
+                }
+            })
+        }
+    }
 
+    impl From<&Style> for super::Style {
+        fn from(input: &Style) -> Self {
             let mut active = iced_style::slider::Style {
                 rail_colors: ([0.6, 0.6, 0.6, 0.5].into(), iced_native::Color::WHITE),
                 handle: iced_native::widget::slider::Handle {
@@ -180,7 +286,7 @@ mod de {
             };
             input.dragging.overwrite(&mut dragging);
 
-            Ok(Self { active, hovered, dragging })
+            Self { active, hovered, dragging }
         }
     }
 }
@@ -198,14 +304,4 @@ mod schema {
             de::Style::json_schema(gen)
         }
     }
-
-    impl Serialize for Style {
-        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-        where
-            S: Serializer,
-        {
-            let _ = serializer;
-            unimplemented!()
-        }
-    }
 }
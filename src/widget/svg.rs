@@ -4,8 +4,8 @@ use crate::import::*;
 
 widget_style_sheet_map!(Svg);
 
-#[derive(Debug, Deserialize, Default)]
-#[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct StyleSheet {
     // layout
     width: Option<Length>,
@@ -31,3 +31,15 @@ impl StyleSheet {
         self.new(iced_native::svg::Handle::from_path(path))
     }
 }
+
+builder! {
+    /// A builder for [`StyleSheet`].
+    StyleSheetBuilder {
+        values {
+            width: iced_native::Length,
+            height: iced_native::Length,
+        }
+        colors {}
+        tables {}
+    }
+}
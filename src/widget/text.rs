@@ -2,8 +2,8 @@ use crate::import::*;
 
 widget_style_sheet_map!(Text);
 
-#[derive(Debug, Deserialize, Default)]
-#[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct StyleSheet {
     // layout
     size: Option<u16>,
@@ -24,7 +24,7 @@ impl StyleSheet {
         if let Some(size) = self.size {
             this = this.size(size);
         }
-        if let Some(color) = self.color {
+        if let Some(color) = &self.color {
             this = this.color(color);
         }
         if let Some(width) = self.width {
@@ -42,3 +42,20 @@ impl StyleSheet {
         this
     }
 }
+
+builder! {
+    /// A builder for [`StyleSheet`].
+    StyleSheetBuilder {
+        values {
+            size: u16,
+            width: iced_native::Length,
+            height: iced_native::Length,
+            horizontal_alignment: iced_native::alignment::Horizontal,
+            vertical_alignment: iced_native::alignment::Vertical,
+        }
+        colors {
+            color,
+        }
+        tables {}
+    }
+}
@@ -2,8 +2,8 @@ use crate::import::*;
 
 widget_style_sheet_map!(TextInput);
 
-#[derive(Debug, Deserialize, Default)]
-#[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct StyleSheet {
     // layout
     width: Option<Length>,
@@ -11,8 +11,8 @@ pub struct StyleSheet {
     size: Option<u16>,
 
     // style
-    #[serde(flatten)]
-    pub(crate) style: Option<Style>,
+    #[serde(flatten, deserialize_with = "flatten_option")]
+    style: Option<Resolved<Style, de::Style>>,
 }
 
 impl StyleSheet {
@@ -43,13 +43,49 @@ impl StyleSheet {
         if let Some(size) = self.size {
             this = this.size(size);
         }
-        if let Some(style) = self.style {
-            this = this.style(style);
+        if let Some(style) = &self.style {
+            this = this.style(style.style);
         }
         this
     }
 }
 
+builder! {
+    /// A builder for [`StyleSheet`].
+    StyleSheetBuilder {
+        values {
+            width: iced_native::Length,
+            padding: u16,
+            size: u16,
+        }
+        colors {
+            placeholder_color,
+            value_color,
+            selection_color,
+        }
+        tables {
+            active: StyleBuilder,
+            focused: StyleBuilder,
+            hovered: StyleBuilder,
+        }
+    }
+}
+
+builder! {
+    /// A builder for the style of a text input state.
+    StyleBuilder {
+        values {
+            border_radius: f32,
+            border_width: f32,
+        }
+        colors {
+            background,
+            border_color,
+        }
+        tables {}
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct Style {
     active: iced_style::text_input::Style,
@@ -89,22 +125,22 @@ impl iced_style::text_input::StyleSheet for Style {
 mod de {
     use crate::import::*;
 
-    #[derive(Debug, Deserialize)]
-    #[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+    #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+    #[cfg_attr(feature = "schema", derive(JsonSchema))]
     pub(super) struct Style {
-        #[serde(default)]
+        #[serde(default, skip_serializing_if = "is_default")]
         active: Inner,
-        #[serde(default)]
+        #[serde(default, skip_serializing_if = "is_default")]
         focused: Inner,
-        #[serde(default)]
+        #[serde(default, skip_serializing_if = "is_default")]
         hovered: Inner,
         placeholder_color: Option<Color>,
         value_color: Option<Color>,
         selection_color: Option<Color>,
     }
 
-    #[derive(Debug, Default, Deserialize)]
-    #[cfg_attr(feature = "schema", derive(Serialize, JsonSchema))]
+    #[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+    #[cfg_attr(feature = "schema", derive(JsonSchema))]
     struct Inner {
         background: Option<Background>,
         border_radius: Option<f32>,
@@ -114,7 +150,7 @@ mod de {
 
     impl Inner {
         fn overwrite(&self, style: &mut iced_style::text_input::Style) {
-            if let Some(background) = self.background {
+            if let Some(background) = &self.background {
                 style.background = background.into();
             }
             if let Some(border_radius) = self.border_radius {
@@ -123,19 +159,14 @@ mod de {
             if let Some(border_width) = self.border_width {
                 style.border_width = border_width;
             }
-            if let Some(border_color) = self.border_color {
+            if let Some(border_color) = &self.border_color {
                 style.border_color = border_color.into();
             }
         }
     }
 
-    impl<'de> Deserialize<'de> for super::Style {
-        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-        where
-            D: Deserializer<'de>,
-        {
-            let input = Style::deserialize(deserializer)?;
-
+    impl From<&Style> for super::Style {
+        fn from(input: &Style) -> Self {
             let mut active = iced_style::text_input::Style {
                 background: iced_native::Background::Color(iced_native::Color::WHITE),
                 border_radius: 5.0,
@@ -155,14 +186,18 @@ mod de {
 
             let placeholder_color = input
                 .placeholder_color
+                .as_ref()
                 .map_or(iced_native::Color::from_rgb(0.7, 0.7, 0.7), Into::into);
-            let value_color =
-                input.value_color.map_or(iced_native::Color::from_rgb(0.3, 0.3, 0.3), Into::into);
+            let value_color = input
+                .value_color
+                .as_ref()
+                .map_or(iced_native::Color::from_rgb(0.3, 0.3, 0.3), Into::into);
             let selection_color = input
                 .selection_color
+                .as_ref()
                 .map_or(iced_native::Color::from_rgb(0.8, 0.8, 1.0), Into::into);
 
-            Ok(Self { active, focused, hovered, placeholder_color, value_color, selection_color })
+            Self { active, focused, hovered, placeholder_color, value_color, selection_color }
         }
     }
 }
@@ -180,14 +215,4 @@ mod schema {
             de::Style::json_schema(gen)
         }
     }
-
-    impl Serialize for Style {
-        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-        where
-            S: Serializer,
-        {
-            let _ = serializer;
-            unimplemented!()
-        }
-    }
 }
@@ -2,7 +2,7 @@
 
 use std::path::Path;
 
-use iced_style_config::Theme;
+use iced_style_config::{color, ParseOptions, Theme};
 
 #[test]
 fn parse() {
@@ -10,3 +10,1012 @@ fn parse() {
     Theme::from_file(path.join("examples/dark_theme.toml")).unwrap();
     Theme::from_file(path.join("examples/light_theme.toml")).unwrap();
 }
+
+#[test]
+fn extends() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/extends");
+
+    let theme = Theme::from_file(dir.join("variant/child.toml")).unwrap();
+    assert_eq!(theme.color()["ACCENT"], iced::Color::from_rgb(0.45, 0.54, 0.85));
+    assert_eq!(theme.color()["SURFACE"], iced::Color::from_rgb8(0x40, 0x44, 0x4B));
+
+    // Colors written in a different form than in the parent replace them.
+    let theme = Theme::from_file(dir.join("color_forms.toml")).unwrap();
+    let expected: Theme = r#"
+        [color]
+        ACCENT = { h = 0, s = 1.0, l = 0.5 }
+        SURFACE = ["0x40", "0x44", "0x4B"]
+
+        [button.default]
+        active.background = "ACCENT"
+        active.text_color = "WHITE"
+        padding = 10
+
+        [text.default]
+        color = { h = 120, s = 1.0, v = 1.0 }
+    "#
+    .parse()
+    .unwrap();
+    assert_eq!(theme, expected);
+
+    let e = Theme::from_file(dir.join("cycle_a.toml")).unwrap_err().to_string();
+    assert!(e.starts_with("cyclic `extends` chain: "), "{e}");
+    assert_eq!(e.matches("cycle_a.toml").count(), 2, "{e}");
+    assert_eq!(e.matches("cycle_b.toml").count(), 1, "{e}");
+
+    let e = Theme::from_file(dir.join("missing.toml")).unwrap_err().to_string();
+    assert!(e.contains("does_not_exist.toml"), "{e}");
+
+    let e = Theme::from_file(dir.join("invalid_parent.toml")).unwrap_err().to_string();
+    let chain = format!(
+        "failed to load `{}` -> `{}`: ",
+        dir.join("invalid_parent.toml").display(),
+        dir.join("invalid.toml").display(),
+    );
+    assert!(e.starts_with(&chain), "{e}");
+}
+
+#[test]
+fn round_trip() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"));
+    for file in ["examples/dark_theme.toml", "examples/light_theme.toml"] {
+        let theme = Theme::from_file(path.join(file)).unwrap();
+        let s = theme.to_string();
+        assert_eq!(s.parse::<Theme>().unwrap(), theme, "{s}");
+    }
+
+    let theme: Theme = r##"
+        [color]
+        ACCENT = ["0x6F", "0xFF", "0xE9"]
+
+        [button.default]
+        active.background = "ACCENT"
+        hovered.text_color = [0.4, 0.48, 0.77]
+        padding = 10
+    "##
+    .parse()
+    .unwrap();
+    let s = theme.to_string();
+    assert!(s.contains(r##"ACCENT = "#6FFFE9""##), "{s}");
+    assert!(s.contains(r#"background = "ACCENT""#), "{s}");
+    assert!(s.contains("text_color = [0.4, 0.48, 0.77]"), "{s}");
+    assert!(s.contains("padding = 10"), "{s}");
+    assert!(!s.contains("border_width"), "{s}");
+    assert!(!s.contains("checkbox"), "{s}");
+}
+
+#[test]
+fn builder() {
+    let expected: Theme = r##"
+        [color]
+        ACCENT = "#6FFFE9"
+
+        [button.primary]
+        padding = 10
+        width = { units = 100 }
+        active.background = "ACCENT"
+        active.border_radius = 3.0
+        hovered.text_color = [0.4, 0.48, 0.77]
+
+        [checkbox.default]
+        active.background = "ACCENT"
+        active.checked.background = "WHITE"
+
+        [slider.default]
+        active.rail_colors = ["ACCENT", "BLACK"]
+        active.handle.shape = { circle = { radius = 4.0 } }
+    "##
+    .parse()
+    .unwrap();
+
+    let theme = Theme::builder()
+        .color("ACCENT", iced::Color::from_rgb8(0x6F, 0xFF, 0xE9))
+        .button("primary", |b| {
+            b.padding(10)
+                .width(iced::Length::Units(100))
+                .active(|s| s.background("ACCENT"))
+                .hovered(|s| s.text_color(iced::Color::from_rgb(0.4, 0.48, 0.77)))
+                .active(|s| s.border_radius(3.0))
+        })
+        .checkbox("default", |b| {
+            b.active(|s| s.background("ACCENT").checked(|s| s.background("WHITE")))
+        })
+        .slider("default", |b| {
+            b.active(|s| {
+                s.rail_colors("ACCENT", "BLACK")
+                    .handle(|h| h.shape(iced::slider::HandleShape::Circle { radius: 4.0 }))
+            })
+        })
+        .build()
+        .unwrap();
+    assert_eq!(theme, expected);
+    assert_eq!(theme.to_string(), expected.to_string());
+
+    let e = Theme::builder().text("default", |b| b.color("UNKNOWN")).build().unwrap_err();
+    assert!(e.to_string().contains("cannot found color alias `unknown`"), "{e}");
+}
+
+#[test]
+fn merge() {
+    let mut theme: Theme = r#"
+        [color]
+        ACCENT = [0.44, 1.0, 0.91]
+
+        [button.primary]
+        padding = 10
+        active.background = "ACCENT"
+        active.text_color = "WHITE"
+    "#
+    .parse()
+    .unwrap();
+    let overlay: Theme = r#"
+        [color]
+        accent = "RED"
+
+        [button.primary]
+        active.text_color = "BLACK"
+    "#
+    .parse()
+    .unwrap();
+    theme.merge(&overlay).unwrap();
+
+    let expected: Theme = r#"
+        [color]
+        accent = "RED"
+
+        [button.primary]
+        padding = 10
+        active.background = "accent"
+        active.text_color = "BLACK"
+    "#
+    .parse()
+    .unwrap();
+    assert_eq!(theme, expected);
+    assert_eq!(theme.color()["ACCENT"], iced::Color::from_rgb(1.0, 0.0, 0.0));
+}
+
+#[test]
+fn merge_color_forms() {
+    let mut theme: Theme = r#"
+        [color]
+        ACCENT = { r = 0, g = 0, b = 255 }
+
+        [text.default]
+        color = { l = 0.7, c = 0.1, h = 30 }
+    "#
+    .parse()
+    .unwrap();
+    let overlay: Theme = r#"
+        [color]
+        ACCENT = { h = 120, s = 1.0, v = 1.0 }
+
+        [text.default]
+        color = { h = 240, s = 1.0, l = 0.5 }
+    "#
+    .parse()
+    .unwrap();
+    theme.merge(&overlay).unwrap();
+    assert_eq!(theme, overlay);
+    assert_eq!(theme.color()["ACCENT"], iced::Color::from_rgb(0.0, 1.0, 0.0));
+}
+
+#[test]
+fn color_alias() {
+    let theme: Theme = r#"
+        [color]
+        BUTTON = { rgb = "TEXT", a = 0.5 }
+        TEXT = "ACCENT"
+        ACCENT = ["0x6F", "0xFF", "0xE9"]
+    "#
+    .parse()
+    .unwrap();
+    assert_eq!(theme.color()["TEXT"], iced::Color::from_rgb8(0x6F, 0xFF, 0xE9));
+    assert_eq!(theme.color()["BUTTON"], iced::Color::from_rgba8(0x6F, 0xFF, 0xE9, 0.5));
+
+    let e = r#"
+        [color]
+        A = "B"
+        B = { rgb = "C", a = 0.5 }
+        C = "a"
+    "#
+    .parse::<Theme>()
+    .unwrap_err()
+    .to_string();
+    assert!(e.contains("cyclic color alias: `A` -> `B` -> `C` -> `A`"), "{e}");
+}
+
+#[test]
+fn shadow_builtin_color() {
+    let s = r#"
+        [color]
+        red = [0.9, 0.2, 0.2]
+        ERROR = "RED"
+    "#;
+    let theme: Theme = s.parse().unwrap();
+    assert_eq!(theme.color()["ERROR"], iced::Color::from_rgb(0.9, 0.2, 0.2));
+
+    let (_, warnings) = Theme::from_str_with_options(s, &ParseOptions::new()).unwrap();
+    assert!(warnings.is_empty());
+    let options = ParseOptions::new().warn_shadowed_builtins(true);
+    let (_, warnings) = Theme::from_str_with_options(s, &options).unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].to_string(),
+        "color alias `red` shadows the built-in color alias for key `color.red` at line 3 column 9"
+    );
+}
+
+#[test]
+fn hex_color() {
+    let theme: Theme = r##"
+        [color]
+        A = "#6FE"
+        B = "#6FE8"
+        C = "#6FFFE9"
+        D = "#6fffe980"
+        E = { rgb = "#6FFFE9", a = 0.5 }
+        "#F00" = "BLUE"
+        F = "#F00"
+    "##
+    .parse()
+    .unwrap();
+    assert_eq!(theme.color()["A"], iced::Color::from_rgb8(0x66, 0xFF, 0xEE));
+    assert_eq!(theme.color()["B"], iced::Color::from_rgba8(0x66, 0xFF, 0xEE, 136.0 / 255.0));
+    assert_eq!(theme.color()["C"], iced::Color::from_rgb8(0x6F, 0xFF, 0xE9));
+    assert_eq!(theme.color()["D"], iced::Color::from_rgba8(0x6F, 0xFF, 0xE9, 128.0 / 255.0));
+    assert_eq!(theme.color()["E"], iced::Color::from_rgba8(0x6F, 0xFF, 0xE9, 0.5));
+    // Strings starting with `#` are never aliases.
+    assert_eq!(theme.color()["F"], iced::Color::from_rgb8(0xFF, 0x00, 0x00));
+
+    let e = r##"text.default.color = "#6FFFE""##.parse::<Theme>().unwrap_err().to_string();
+    assert!(
+        e.contains(
+            "invalid hex color `#6FFFE`: expected 3, 4, 6, or 8 hexadecimal digits, found 5"
+        ),
+        "{e}"
+    );
+    let e = r##"text.default.color = "#6FFFEG""##.parse::<Theme>().unwrap_err().to_string();
+    assert!(e.contains("invalid hex color `#6FFFEG`: `G` is not a hexadecimal digit"), "{e}");
+}
+
+#[test]
+fn css_color_function() {
+    let theme: Theme = r#"
+        [color]
+        A = "rgb(111 255 233)"
+        B = "rgb(100%, 0%, 0%)"
+        C = "rgba(255, 0, 0, 0.5)"
+        D = "RGB(255 0 0 / 50%)"
+        E = "hsl(120deg 100% 50%)"
+        F = "hsl(0.5turn, 100%, 50%)"
+        G = "hsla(240, 100%, 50%, 0.25)"
+        H = "hwb(0 0% 0%)"
+        I = "hwb(90 60% 60% / 0.5)"
+        J = { rgb = "hsl(120 100 50)", a = 0.5 }
+    "#
+    .parse()
+    .unwrap();
+    assert_eq!(theme.color()["A"], iced::Color::from_rgb8(111, 255, 233));
+    assert_eq!(theme.color()["B"], iced::Color::from_rgb(1.0, 0.0, 0.0));
+    assert_eq!(theme.color()["C"], iced::Color::from_rgba(1.0, 0.0, 0.0, 0.5));
+    assert_eq!(theme.color()["D"], iced::Color::from_rgba(1.0, 0.0, 0.0, 0.5));
+    assert_eq!(theme.color()["E"], iced::Color::from_rgb(0.0, 1.0, 0.0));
+    assert_eq!(theme.color()["F"], iced::Color::from_rgb(0.0, 1.0, 1.0));
+    assert_eq!(theme.color()["G"], iced::Color::from_rgba(0.0, 0.0, 1.0, 0.25));
+    assert_eq!(theme.color()["H"], iced::Color::from_rgb(1.0, 0.0, 0.0));
+    assert_eq!(theme.color()["I"], iced::Color::from_rgba(0.5, 0.5, 0.5, 0.5));
+    assert_eq!(theme.color()["J"], iced::Color::from_rgba(0.0, 1.0, 0.0, 0.5));
+
+    for (color, msg) in [
+        (
+            "rgb(1, 2)",
+            "invalid color `rgb(1, 2)`: expected 3 arguments and an optional alpha, found 2",
+        ),
+        ("rgb(1 2 x)", "invalid color `rgb(1 2 x)`: `x` is not a number"),
+        ("hsl(1x 2% 3%)", "invalid color `hsl(1x 2% 3%)`: `1x` is not an angle"),
+        ("hwb(0, 0%, 0%)", "invalid color `hwb(0, 0%, 0%)`: arguments must be separated by spaces"),
+        (
+            "rgb(1 2 3 / 4 5)",
+            "invalid color `rgb(1 2 3 / 4 5)`: expected exactly one argument after `/`",
+        ),
+        ("rgb(1, 2, 3", "invalid color `rgb(1, 2, 3`: expected `)` at the end"),
+        ("foo(1)", "unknown color function `foo`"),
+    ] {
+        let e = format!("text.default.color = {color:?}").parse::<Theme>().unwrap_err().to_string();
+        assert!(e.contains(msg), "{e}");
+    }
+}
+
+#[test]
+fn color_manipulation_function() {
+    fn assert_approx_eq(a: iced::Color, b: iced::Color) {
+        let (a, b) = (a.into_linear(), b.into_linear());
+        assert!(a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-5), "{a:?} != {b:?}");
+    }
+
+    let theme: Theme = r##"
+        [color]
+        A = "lighten(ACCENT, 10%)"
+        B = "darken(hsl(120 100% 50%), 0.25)"
+        C = "saturate(hsl(0 50% 50%), 25%)"
+        D = "desaturate(hsl(0 50% 50%), 50%)"
+        E = "mix(#FF0000, #0000FF, 0.25)"
+        F = "mix(#FF0000, #0000FF)"
+        G = "alpha(SURFACE, 0.5)"
+        H = "complement(#FF0000)"
+        I = "alpha(complement(lighten(BASE, 100%)), 50%)"
+        ACCENT = "hsl(120 100% 40%)"
+        SURFACE = "#336699"
+        BASE = "#000"
+
+        [text.default]
+        color = "darken(ACCENT, 40%)"
+    "##
+    .parse()
+    .unwrap();
+    assert_approx_eq(theme.color()["A"], iced::Color::from_rgb(0.0, 1.0, 0.0));
+    assert_approx_eq(theme.color()["B"], iced::Color::from_rgb(0.0, 0.5, 0.0));
+    assert_approx_eq(theme.color()["C"], iced::Color::from_rgb(0.875, 0.125, 0.125));
+    assert_approx_eq(theme.color()["D"], iced::Color::from_rgb(0.5, 0.5, 0.5));
+    assert_approx_eq(theme.color()["E"], iced::Color::from_rgb(0.25, 0.0, 0.75));
+    assert_approx_eq(theme.color()["F"], iced::Color::from_rgb(0.5, 0.0, 0.5));
+    assert_approx_eq(theme.color()["G"], iced::Color::from_rgba8(0x33, 0x66, 0x99, 0.5));
+    assert_approx_eq(theme.color()["H"], iced::Color::from_rgb(0.0, 1.0, 1.0));
+    assert_approx_eq(theme.color()["I"], iced::Color::from_rgba(1.0, 1.0, 1.0, 0.5));
+    assert!(theme.to_string().contains(r#"color = "darken(ACCENT, 40%)""#));
+
+    for (color, msg) in [
+        ("lighten(FOO, 10%)", "invalid color `lighten(FOO, 10%)`: cannot found color alias"),
+        ("lighten(#000)", "invalid color `lighten(#000)`: expected 2 arguments, found 1"),
+        ("mix(#000 #FFF)", "invalid color `mix(#000 #FFF)`: arguments must be separated by commas"),
+        ("mix(#000, #FFF, 2)", "invalid color `mix(#000, #FFF, 2)`: weight `2` is out of range"),
+        ("complement(#000, #FFF)", "expected 1 argument, found 2"),
+    ] {
+        let e = format!("text.default.color = {color:?}").parse::<Theme>().unwrap_err().to_string();
+        assert!(e.contains(msg), "{e}");
+    }
+}
+
+#[test]
+fn perceptual_color_space() {
+    fn assert_approx_eq(a: iced::Color, b: iced::Color) {
+        let (a, b) = (a.into_linear(), b.into_linear());
+        assert!(a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-3), "{a:?} != {b:?}");
+    }
+
+    let theme: Theme = r#"
+        [color]
+        A = { l = 0.627955, c = 0.257683, h = 29.2339 }
+        B = { l = 0.627955, a = 0.224863, b = 0.125846, alpha = 0.5 }
+        C = { h = 120, s = 1.0, l = 0.25 }
+        D = { h = 120, s = 1.0, v = 0.5 }
+        E = { l = 0.7, c = 0.4, h = 150 }
+        F = { l = 1.2, c = 0.1, h = 0 }
+        G = { h = 120, s = 1.0, l = 0.25, a = 0.5 }
+        H = { h = 120, s = 1.0, v = 0.5, a = 0.5 }
+        I = { l = 0.627955, c = 0.257683, h = 29.2339, a = 0.5 }
+    "#
+    .parse()
+    .unwrap();
+    assert_approx_eq(theme.color()["A"], iced::Color::from_rgb(1.0, 0.0, 0.0));
+    assert_approx_eq(theme.color()["B"], iced::Color::from_rgba(1.0, 0.0, 0.0, 0.5));
+    assert_approx_eq(theme.color()["C"], iced::Color::from_rgb(0.0, 0.5, 0.0));
+    assert_approx_eq(theme.color()["D"], iced::Color::from_rgb(0.0, 0.5, 0.0));
+    // Out-of-gamut colors are mapped into the gamut, keeping the lightness and hue.
+    let e = theme.color()["E"];
+    assert!(e.into_linear().iter().all(|c| (0.0..=1.0).contains(c)), "{e:?}");
+    let [l, _, h] = color::to_oklch(e);
+    assert!((l - 0.7).abs() < 0.02 && (h - 150.0).abs() < 5.0, "{l} {h}");
+    assert_approx_eq(theme.color()["F"], iced::Color::WHITE);
+    // `a` is an alias of `alpha` except in OKLab, where it is an axis.
+    assert_approx_eq(theme.color()["G"], iced::Color::from_rgba(0.0, 0.5, 0.0, 0.5));
+    assert_approx_eq(theme.color()["H"], iced::Color::from_rgba(0.0, 0.5, 0.0, 0.5));
+    assert_approx_eq(theme.color()["I"], iced::Color::from_rgba(1.0, 0.0, 0.0, 0.5));
+    assert_eq!(theme.to_string().parse::<Theme>().unwrap(), theme);
+
+    let color = iced::Color::from_rgb(0.2, 0.4, 0.6);
+    let [h, s, l] = color::to_hsl(color);
+    assert_approx_eq(color::from_hsl(h, s, l), color);
+    let [h, s, v] = color::to_hsv(color);
+    assert_approx_eq(color::from_hsv(h, s, v), color);
+    let [l, a, b] = color::to_oklab(color);
+    assert_approx_eq(color::from_oklab(l, a, b), color);
+    let [l, c, h] = color::to_oklch(color);
+    assert_approx_eq(color::from_oklch(l, c, h), color);
+}
+
+#[test]
+fn error_location() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/error");
+
+    let path = dir.join("alias.toml");
+    let e = Theme::from_file(&path).unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        format!(
+            "cannot found color alias `surfac` for key `button.primary.hovered.background` at \
+             `{}:5:9`, did you mean `surface`?",
+            path.display()
+        )
+    );
+    assert_eq!(
+        e.snippet().to_string(),
+        format!(
+            "\
+error: cannot found color alias `surfac`, did you mean `surface`?
+ --> {}:5:9
+  |
+5 | hovered.background = \"SURFAC\"
+  |         ^^^^^^^^^^^^^^^^^^^^^ button.primary.hovered.background
+",
+            path.display()
+        )
+    );
+
+    // The error is located in the file that defines the invalid value.
+    let e = Theme::from_file(dir.join("child.toml")).unwrap_err().to_string();
+    assert!(e.ends_with(&format!("at `{}:2:1`", dir.join("base.toml").display())), "{e}");
+
+    let e = "[color]\nA = \"B\"\n\n[button.default\npadding = 1".parse::<Theme>().unwrap_err();
+    assert_eq!(e.to_string(), "expected a right bracket, found a newline at line 4 column 16");
+    let e = "[color]\nA = \"lighten(B, 1%)\"".parse::<Theme>().unwrap_err();
+    assert!(e.to_string().ends_with("for key `color.A` at line 2 column 1"), "{e}");
+}
+
+#[test]
+fn suggestion() {
+    let e = r#"text.default.color = "DODGERBLUE""#.parse::<Theme>().unwrap_err().to_string();
+    assert!(e.starts_with("cannot found color alias `dodgerblue`"), "{e}");
+    assert!(e.ends_with(", did you mean `dodger_blue`?"), "{e}");
+    let e = "[color]\nACCENT = [0.1, 0.2, 0.3]\nA = \"ACCNT\"".parse::<Theme>().unwrap_err();
+    assert!(e.to_string().contains("did you mean `accent`?"), "{e}");
+    let e = "[container.default]\nwidth = \"fil\"".parse::<Theme>().unwrap_err();
+    assert!(e.to_string().contains("unknown variant `fil`"), "{e}");
+    assert!(e.to_string().contains("did you mean `fill`?"), "{e}");
+}
+
+#[test]
+fn strict() {
+    let options = ParseOptions::new().strict(true);
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let (_, warnings) =
+        Theme::from_file_with_options(path.join("examples/light_theme.toml"), &options).unwrap();
+    assert!(warnings.is_empty());
+
+    let s = "[button.default]\nhoverd.background = \"RED\"";
+    assert!(s.parse::<Theme>().is_ok());
+    let e = Theme::from_str_with_options(s, &options).unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "unknown key `button.default.hoverd` at line 2 column 1, did you mean `hovered`?"
+    );
+    let e = Theme::from_str_with_options("[buton.default]\npadding = 1", &options).unwrap_err();
+    assert_eq!(e.to_string(), "unknown key `buton` at line 1 column 2, did you mean `button`?");
+    let e = Theme::from_str_with_options("[container.default]\nwidht = 1", &options).unwrap_err();
+    assert!(e.to_string().starts_with("unknown key `container.default.widht`"), "{e}");
+}
+
+#[test]
+fn warning() {
+    let (_, warnings) = Theme::from_str_with_options(
+        r##"
+[color]
+ACCENT = "#6FFFE9"
+UNUSED = "#000000"
+BORDER = "lighten(ACCENT, 10%)"
+
+[button.Default]
+active.background = ["0x6F", "0xFF", "0xE9"]
+active.border_color = "BORDER"
+
+[slider.default]
+active.rail_colors = [{ r = "0x6F", g = "0xFF", b = "0xE9", a = "0.5" }, "BORDER"]
+"##,
+        &ParseOptions::new().warn_unused_colors(true),
+    )
+    .unwrap();
+    let warnings: Vec<_> = warnings.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        warnings,
+        [
+            "color alias `UNUSED` is never used for key `color.UNUSED` at line 4 column 1",
+            "style sheet `Default` is never used as the default style sheet because style sheet \
+             names are case-sensitive for key `button.Default` at line 7 column 9, did you mean \
+             `default`?",
+        ]
+    );
+
+    // The warning is located in the file that defines the value.
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/warning");
+    let (_, warnings) =
+        Theme::from_file_with_options(dir.join("child.toml"), &ParseOptions::new()).unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].path(), Some(&*dir.join("base.toml")));
+    assert_eq!(warnings[0].line_col(), Some((4, 9)));
+    assert!(warnings[0].snippet().to_string().starts_with("warning: style sheet `Default`"));
+
+    #[cfg(feature = "hot-reloading")]
+    {
+        let theme = iced_style_config::ReloadableTheme::from_file_with_options(
+            dir.join("child.toml"),
+            &ParseOptions::new(),
+        )
+        .unwrap();
+        assert_eq!(theme.warnings(), warnings);
+    }
+}
+
+#[test]
+fn warn_unknown_keys() {
+    let options = ParseOptions::new().warn_unknown_keys(true);
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"));
+    for file in ["examples/dark_theme.toml", "examples/light_theme.toml"] {
+        let (_, warnings) = Theme::from_file_with_options(path.join(file), &options).unwrap();
+        // `rule` is not yet supported.
+        assert!(
+            warnings.iter().all(|w| w.to_string().starts_with("unknown key `rule` at")),
+            "{warnings:?}"
+        );
+    }
+    let (_, warnings) = Theme::from_str_with_options(
+        r#"
+        [buton.default]
+        padding = 1
+
+        [button.default]
+        hoverd.background = "RED"
+        active.xyz = 1
+
+        [slider.default]
+        active.rail_colors = ["RED", "BLUE"]
+        "#,
+        &options,
+    )
+    .unwrap();
+    let warnings: Vec<_> = warnings.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        warnings,
+        [
+            "unknown key `buton` at line 2 column 10, did you mean `button`?",
+            "unknown key `button.default.active.xyz` at line 7 column 16",
+            "unknown key `button.default.hoverd` at line 6 column 9, did you mean `hovered`?",
+        ]
+    );
+}
+
+#[test]
+fn multiple_errors() {
+    let s = r#"
+[color]
+A = "UNKNOWN"
+B = "lighten(A, 10%)"
+C = "darken(D, 10%)"
+
+[button.default]
+active.background = "A"
+hovered.background = "X"
+padding = "1"
+
+[slider.default]
+active.rail_colors = ["Y", "Z"]
+active.handle.color = "B"
+"#;
+    let e = s.parse::<Theme>().unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "6 errors:
+- cannot found color alias `unknown` for key `color.A` at line 3 column 1
+- invalid color `darken(D, 10%)`: cannot found color alias `d` for key `color.C` at line 5 column 1
+- cannot found color alias `x` for key `button.default.hovered.background` at line 9 column 9
+- invalid type: string \"1\", expected u16 for key `button.default.padding` at line 10 column 1
+- cannot found color alias `y` for key `slider.default.active.rail_colors.0` at line 13 column 23
+- cannot found color alias `z` for key `slider.default.active.rail_colors.1` at line 13 column 28"
+    );
+    let snippet = e.snippet().to_string();
+    assert_eq!(snippet.matches("error: ").count(), 6, "{snippet}");
+    assert!(snippet.contains("\nerror: cannot found color alias `x`\n --> 9:9\n"), "{snippet}");
+
+    // Errors in the same style, including in flattened fields, are all reported.
+    let s = r#"
+[checkbox.default]
+active.background = "X"
+active.checked.text_color = "Y"
+active.border_width = "1"
+"#;
+    let e = s.parse::<Theme>().unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "3 errors:
+- cannot found color alias `x` for key `checkbox.default.active.background` at line 3 column 8
+- cannot found color alias `y` for key `checkbox.default.active.checked.text_color` at line 4 column 16
+- invalid type: string \"1\", expected f32 for key `checkbox.default.active.border_width` at line 5 column 8"
+    );
+}
+
+#[test]
+fn error_kind() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let e = Theme::from_file(dir.join("missing.toml")).unwrap_err();
+    assert!(e.is_io() && e.is_not_found() && !e.is_syntax(), "{e}");
+    assert_eq!(e.path(), Some(&*dir.join("missing.toml")));
+    assert!(!e.to_string().contains("failed to load"), "{e}");
+    assert_eq!(e.span(), None);
+    let e = Theme::from_file(dir.join("extends/missing.toml")).unwrap_err();
+    assert!(e.is_not_found(), "{e}");
+    assert!(e.path().unwrap().ends_with("does_not_exist.toml"), "{e}");
+    let e = Theme::from_file(dir.join("extends/cycle_a.toml")).unwrap_err();
+    assert!(e.is_extends_cycle() && !e.is_io(), "{e}");
+
+    let e = "[button.default\npadding = 1".parse::<Theme>().unwrap_err();
+    assert!(e.is_syntax() && !e.is_invalid_value(), "{e}");
+    assert_eq!(e.path(), None);
+    assert_eq!(e.line_col(), Some((1, 16)));
+
+    let e = "[text.default]\ncolor = \"UNKNOWN\"".parse::<Theme>().unwrap_err();
+    assert!(e.is_unknown_color_alias() && !e.is_invalid_value() && !e.is_watch(), "{e}");
+    assert_eq!(e.key().as_deref(), Some("text.default.color"));
+    assert_eq!(e.line_col(), Some((2, 1)));
+    assert_eq!(e.span(), Some(15..32));
+    let e = "[button.default]\nactive.background = \"mix(RED, FOO)\"".parse::<Theme>().unwrap_err();
+    assert!(e.is_unknown_color_alias() && !e.is_invalid_value(), "{e}");
+    assert_eq!(e.key().as_deref(), Some("button.default.active.background"));
+    let e = "[text.default]\ncolor = \"#12345\"".parse::<Theme>().unwrap_err();
+    assert!(e.is_invalid_value() && !e.is_unknown_color_alias(), "{e}");
+
+    let e = "[button.default]\npadding = \"1\"\nhoverd.background = \"RED\""
+        .parse::<Theme>()
+        .unwrap_err();
+    assert!(e.is_invalid_value() && !e.is_unknown_key(), "{e}");
+    let options = ParseOptions::new().strict(true);
+    let e = Theme::from_str_with_options("[button.default]\nhoverd.background = \"RED\"", &options)
+        .unwrap_err();
+    assert!(e.is_unknown_key() && !e.is_invalid_value(), "{e}");
+}
+
+#[cfg(feature = "hot-reloading")]
+#[cfg(target_os = "linux")]
+mod reload {
+    use std::{
+        fs,
+        hash::Hasher,
+        io,
+        path::PathBuf,
+        sync::{mpsc, Mutex},
+        thread,
+        time::Duration,
+    };
+
+    use iced_native::futures::{executor::block_on, stream::BoxStream, FutureExt, StreamExt};
+    use iced_style_config::{
+        reloadable::{
+            EnvSource, ReaderSource, ReloadEvent, ReloadOutcome, StringSource, WatchOptions,
+        },
+        ParseOptions, ReloadableTheme,
+    };
+
+    /// How long to wait for an event that is expected. This is generous
+    /// because file system events can be slow on a loaded machine.
+    const TIMEOUT: Duration = Duration::from_secs(10);
+    /// How long to wait before concluding that no event is published. This is
+    /// longer than the debounce interval of any test.
+    const QUIET: Duration = Duration::from_secs(1);
+
+    /// Serializes the tests that modify the environment.
+    static ENV: Mutex<()> = Mutex::new(());
+
+    fn events(theme: &ReloadableTheme) -> mpsc::Receiver<ReloadEvent> {
+        let recipe = theme.subscription().recipes().pop().unwrap();
+        forward(recipe.stream(Box::pin(iced_native::futures::stream::empty())))
+    }
+
+    /// Polls `stream` on another thread and sends its items to the returned
+    /// channel.
+    fn forward<T: Send + 'static>(mut stream: BoxStream<'static, T>) -> mpsc::Receiver<T> {
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            block_on(async {
+                while let Some(item) = stream.next().await {
+                    if tx.send(item).is_err() {
+                        break;
+                    }
+                }
+            });
+        });
+        rx
+    }
+
+    /// Waits for the next item published to `rx`.
+    #[track_caller]
+    fn next<T>(rx: &mpsc::Receiver<T>) -> T {
+        rx.recv_timeout(TIMEOUT).expect("no event published")
+    }
+
+    /// Asserts that nothing is published to `rx` for a while.
+    #[track_caller]
+    fn assert_quiet<T>(rx: &mpsc::Receiver<T>) {
+        assert!(
+            matches!(rx.recv_timeout(QUIET), Err(mpsc::RecvTimeoutError::Timeout)),
+            "unexpected event published"
+        );
+    }
+
+    /// Creates a directory containing a theme file, and returns the path to
+    /// the file.
+    fn theme_file(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("iced_style_config-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("theme.toml");
+        fs::write(&path, "[button.default]\npadding = 0").unwrap();
+        path
+    }
+
+    #[test]
+    fn debounce() {
+        let dir =
+            std::env::temp_dir().join(format!("iced_style_config-{}-debounce", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("theme.toml");
+        fs::write(&path, "[button.default]\npadding = 0").unwrap();
+
+        let mut theme = ReloadableTheme::from_file(&path).unwrap();
+        theme.set_debounce(Duration::from_millis(300));
+        let events = events(&theme);
+
+        // A burst of writes is coalesced into one event.
+        for i in 1..=5 {
+            fs::write(&path, format!("[button.default]\npadding = {i}")).unwrap();
+            thread::sleep(Duration::from_millis(20));
+        }
+        next(&events);
+        assert_quiet(&events);
+        theme.reload().unwrap();
+
+        // Writing the same content does not publish an event.
+        fs::write(&path, "[button.default]\npadding = 5").unwrap();
+        assert_quiet(&events);
+
+        fs::write(&path, "[button.default]\npadding = 6").unwrap();
+        next(&events);
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn atomic_save() {
+        let path = theme_file("atomic_save");
+        let dir = path.parent().unwrap();
+        let mut theme = ReloadableTheme::from_file(&path).unwrap();
+        let events = events(&theme);
+        assert!(!theme.is_polling());
+
+        // Write a temporary file and rename it to the original file, as many
+        // editors do. Do it twice to check that the watch survives the
+        // replacement of the file.
+        for i in 1..=2 {
+            let tmp = dir.join(format!(".theme.toml.{i}.tmp"));
+            fs::write(&tmp, format!("[button.default]\npadding = {i}")).unwrap();
+            fs::rename(&tmp, &path).unwrap();
+            next(&events);
+            theme.reload().unwrap();
+            assert!(theme.to_string().contains(&format!("padding = {i}")), "{}", *theme);
+        }
+
+        // Back up the original file by renaming it, then write a new file, as
+        // vim does by default.
+        fs::rename(&path, dir.join("theme.toml~")).unwrap();
+        fs::write(&path, "[button.default]\npadding = 3").unwrap();
+        fs::remove_file(dir.join("theme.toml~")).unwrap();
+        next(&events);
+        theme.reload().unwrap();
+        assert!(theme.to_string().contains("padding = 3"), "{}", *theme);
+
+        // Delete and recreate the file. The current theme is kept while the
+        // file does not exist.
+        fs::remove_file(&path).unwrap();
+        next(&events);
+        assert!(theme.reload().unwrap_err().is_not_found());
+        assert!(theme.to_string().contains("padding = 3"), "{}", *theme);
+        fs::write(&path, "[button.default]\npadding = 4").unwrap();
+        next(&events);
+        theme.reload().unwrap();
+        assert!(theme.to_string().contains("padding = 4"), "{}", *theme);
+
+        // Changes to other files in the directory are ignored.
+        fs::write(dir.join("other.toml"), "[button.default]\npadding = 5").unwrap();
+        assert_quiet(&events);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn reload_subscription() {
+        let path = theme_file("reload_subscription");
+        let mut theme = ReloadableTheme::from_file(&path).unwrap();
+        let recipe = theme.reload_subscription().recipes().pop().unwrap();
+        let outcomes = forward(recipe.stream(Box::pin(iced_native::futures::stream::empty())));
+
+        fs::write(&path, "[button.default]\npadding = 1").unwrap();
+        let outcome: ReloadOutcome = next(&outcomes);
+        assert_eq!(outcome.path, Some(path.clone()));
+        theme.apply(outcome).unwrap();
+        assert!(theme.to_string().contains("padding = 1"), "{}", *theme);
+        assert!(!theme.is_degraded());
+        let last_success = theme.last_success().unwrap();
+
+        // On error, the current theme is kept.
+        fs::write(&path, "[button.default]\npadding = \"a\"").unwrap();
+        let err = theme.apply(next(&outcomes)).unwrap_err();
+        assert!(err.is_invalid_value(), "{err}");
+        assert!(theme.to_string().contains("padding = 1"), "{}", *theme);
+        assert!(theme.is_degraded());
+        assert_eq!(theme.consecutive_failures(), 1);
+        assert_eq!(theme.last_error().unwrap().to_string(), err.to_string());
+        assert!(theme.reload().is_err());
+        assert_eq!(theme.consecutive_failures(), 2);
+        assert_eq!(theme.last_success(), Some(last_success));
+
+        // A successful reload recovers, but the last error is kept.
+        fs::write(&path, "[button.default]\npadding = 2").unwrap();
+        theme.apply(next(&outcomes)).unwrap();
+        assert!(!theme.is_degraded());
+        assert_eq!(theme.consecutive_failures(), 0);
+        assert!(theme.last_error().is_some());
+        assert!(theme.last_success().unwrap() >= last_success);
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn multiple_themes() {
+        fn hash(theme: &ReloadableTheme) -> u64 {
+            let mut hasher = iced_native::Hasher::default();
+            theme.subscription().recipes().pop().unwrap().hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let (path1, path2) = (theme_file("multiple_themes1"), theme_file("multiple_themes2"));
+        let mut theme1 = ReloadableTheme::from_file(&path1).unwrap();
+        let theme2 = ReloadableTheme::from_file(&path2).unwrap();
+        assert_ne!(theme1.id(), theme2.id());
+        assert_eq!(hash(&theme1), hash(&theme1));
+        assert_ne!(hash(&theme1), hash(&theme2));
+
+        let events1 = events(&theme1);
+        let events2 = events(&theme2);
+        fs::write(&path2, "[button.default]\npadding = 1").unwrap();
+        let event = next(&events2);
+        assert_eq!(Some(event.id()), theme2.id());
+        assert_quiet(&events1);
+
+        // Changing the path keeps the identifier, but restarts the subscription.
+        let (id, old_hash) = (theme1.id(), hash(&theme1));
+        theme1.set_path(&path2).unwrap();
+        assert_eq!(theme1.path(), Some(&*path2));
+        assert_eq!(theme1.id(), id);
+        assert_ne!(hash(&theme1), old_hash);
+        assert!(theme1.to_string().contains("padding = 1"), "{}", *theme1);
+
+        // A path that fails to load is recorded in the health and keeps the old path.
+        let missing = path1.with_file_name("missing.toml");
+        let err = theme1.set_path(&missing).unwrap_err();
+        assert!(err.is_not_found(), "{err}");
+        assert_eq!(theme1.path(), Some(&*path2));
+        assert!(theme1.is_degraded());
+        assert_eq!(theme1.consecutive_failures(), 1);
+        assert_eq!(theme1.last_error().unwrap().to_string(), err.to_string());
+        theme1.set_path(&path1).unwrap();
+        assert!(!theme1.is_degraded());
+
+        fs::remove_dir_all(path1.parent().unwrap()).unwrap();
+        fs::remove_dir_all(path2.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn poll() {
+        let path = theme_file("poll");
+        let watch_options = WatchOptions::new()
+            .poll(true)
+            .poll_interval(Duration::from_millis(50))
+            .compare_contents(true);
+        let mut theme = ReloadableTheme::from_file_with_watch_options(
+            &path,
+            &ParseOptions::new(),
+            &watch_options,
+        )
+        .unwrap();
+        assert!(theme.is_polling());
+        let events = events(&theme);
+
+        fs::write(&path, "[button.default]\npadding = 1").unwrap();
+        next(&events);
+        theme.reload().unwrap();
+        assert!(theme.to_string().contains("padding = 1"), "{}", *theme);
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn sources() {
+        let mut theme =
+            ReloadableTheme::new(StringSource::new("[button.default]\npadding = 0")).unwrap();
+        assert_eq!(theme.path(), None);
+        let recipe = theme.subscription().recipes().pop().unwrap();
+        let mut events = recipe.stream(Box::pin(iced_native::futures::stream::empty()));
+        theme.source().unwrap().set("[button.default]\npadding = 10");
+        theme.source().unwrap().set("[button.default]\npadding = 1");
+        // Changes before the stream is polled are coalesced into one event.
+        let event = events.next().now_or_never().unwrap().unwrap();
+        assert_eq!(Some(event.id()), theme.id());
+        assert!(events.next().now_or_never().is_none());
+        theme.reload().unwrap();
+        assert!(theme.to_string().contains("padding = 1"), "{}", *theme);
+
+        let reader = ReaderSource::new(|| Ok(io::Cursor::new("[button.default]\npadding = 2")));
+        let theme = ReloadableTheme::new(reader).unwrap();
+        assert!(theme.to_string().contains("padding = 2"), "{}", *theme);
+
+        let name = format!("ICED_STYLE_CONFIG_TEST_THEME_{}", std::process::id());
+        let _env = ENV.lock().unwrap();
+        std::env::set_var(&name, "[button.default]\npadding = 3");
+        let theme = ReloadableTheme::new(EnvSource::new(&name));
+        std::env::remove_var(&name);
+        let theme = theme.unwrap();
+        assert!(theme.to_string().contains("padding = 3"), "{}", *theme);
+        let e = ReloadableTheme::new(EnvSource::new(&name)).unwrap_err();
+        assert!(e.is_not_found(), "{e}");
+    }
+
+    #[test]
+    fn extends() {
+        let path = theme_file("extends");
+        let dir = path.parent().unwrap();
+        let palette = dir.join("palette/base.toml");
+        fs::create_dir(palette.parent().unwrap()).unwrap();
+        fs::write(&palette, "[color]\nACCENT = \"#000000\"").unwrap();
+        let theme =
+            "extends = \"palette/base.toml\"\n[button.default]\nactive.background = \"ACCENT\"";
+        fs::write(&path, theme).unwrap();
+        let mut theme = ReloadableTheme::from_file(&path).unwrap();
+        let events = events(&theme);
+
+        // Rewriting the inherited file with the same content is not a change.
+        fs::write(&palette, "[color]\nACCENT = \"#000000\"").unwrap();
+        assert_quiet(&events);
+
+        // Editing the inherited file reloads the theme.
+        fs::write(&palette, "[color]\nACCENT = \"#FF0000\"").unwrap();
+        next(&events);
+        theme.reload().unwrap();
+        assert!(theme.to_string().contains("#FF0000"), "{}", *theme);
+
+        // The watched files are updated on reload.
+        fs::write(&path, "[button.default]\npadding = 1").unwrap();
+        next(&events);
+        theme.reload().unwrap();
+        fs::write(&palette, "[color]\nACCENT = \"#00FF00\"").unwrap();
+        assert_quiet(&events);
+
+        // A missing inherited file is watched, and creating it reloads the theme.
+        let new = dir.join("palette/new.toml");
+        fs::write(&path, "extends = \"palette/new.toml\"").unwrap();
+        next(&events);
+        assert!(theme.reload().unwrap_err().is_not_found());
+        fs::write(&new, "[color]\nACCENT = \"#0000FF\"").unwrap();
+        next(&events);
+        theme.reload().unwrap();
+
+        // Fixing the file that refers to a missing inherited file reloads the
+        // theme.
+        fs::write(&path, "extends = \"palette/missing.toml\"").unwrap();
+        next(&events);
+        assert!(theme.reload().unwrap_err().is_not_found());
+        fs::write(&path, "extends = \"palette/new.toml\"").unwrap();
+        next(&events);
+        theme.reload().unwrap();
+        assert!(theme.to_string().contains("#0000FF"), "{}", *theme);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -7,9 +7,11 @@ use fs_err as fs;
 use proc_macro2::TokenStream;
 use quote::quote;
 use schemars::schema_for;
+use serde_json::Value;
 
 fn main() -> Result<()> {
     gen_color_map()?;
+    gen_keys()?;
     gen_schema()?;
     Ok(())
 }
@@ -68,8 +70,8 @@ fn gen_color_map() -> Result<()> {
     let capacity = inserts.len();
     let tokens = quote! {
         use crate::*;
-        pub(crate) static DEFAULT_COLOR_ALIASES: once_cell::sync::Lazy<color::ColorMap> =
-            once_cell::sync::Lazy::new(|| {
+        pub(crate) static DEFAULT_COLOR_ALIASES: std::sync::LazyLock<color::ColorMap> =
+            std::sync::LazyLock::new(|| {
                 let mut map = color::ColorMap::with_capacity(#capacity);
                 #(#inserts)*
                 map
@@ -80,6 +82,64 @@ fn gen_color_map() -> Result<()> {
     Ok(())
 }
 
+/// Generates the keys of the configuration file from the schema of the
+/// deserializers.
+fn gen_keys() -> Result<()> {
+    /// Returns the schema of `schema` without the `null` case of an `Option`,
+    /// resolving references.
+    fn resolve<'a>(schema: &'a Value, definitions: &'a Value) -> &'a Value {
+        if let Some(r) = schema["$ref"].as_str() {
+            return resolve(&definitions[r.trim_start_matches("#/definitions/")], definitions);
+        }
+        for k in ["anyOf", "allOf"] {
+            if let Some(schemas) = schema[k].as_array() {
+                let mut non_null = schemas.iter().filter(|s| s["type"] != "null");
+                if let (Some(schema), None) = (non_null.next(), non_null.next()) {
+                    return resolve(schema, definitions);
+                }
+            }
+        }
+        schema
+    }
+
+    fn table_keys(properties: &Value, definitions: &Value) -> Vec<TokenStream> {
+        let mut keys = vec![];
+        for (name, schema) in properties.as_object().unwrap() {
+            let schema = resolve(schema, definitions);
+            let items = resolve(&schema["additionalProperties"], definitions);
+            let key = if let Some(properties) = schema.get("properties") {
+                let keys = table_keys(properties, definitions);
+                quote! { Key::table(#name, &[#(#keys,)*]) }
+            } else if let Some(properties) = items.get("properties") {
+                // Style sheets with arbitrary names.
+                let keys = table_keys(properties, definitions);
+                quote! { Key::map(#name, &[#(#keys,)*]) }
+            } else {
+                quote! { Key::value(#name) }
+            };
+            // Widgets behind features.
+            keys.push(match &**name {
+                "qr_code" => quote! { #[cfg(feature = "qr_code")] #key },
+                _ => key,
+            });
+        }
+        keys
+    }
+
+    let workspace_root = &workspace_root();
+    let schema = serde_json::to_value(schema_for!(iced_style_config::Theme))?;
+    let keys = table_keys(&schema["properties"], &schema["definitions"]);
+
+    let tokens = quote! {
+        use crate::keys::Key;
+        /// The keys of the top-level table.
+        pub(crate) const KEYS: &[Key] = &[#(#keys,)*];
+    };
+
+    write(&workspace_root.join("src/gen/keys.rs"), tokens)?;
+    Ok(())
+}
+
 fn gen_schema() -> Result<()> {
     let workspace_root = &workspace_root();
     let schema = schema_for!(iced_style_config::Theme);